@@ -0,0 +1,20 @@
+// Throw arbitrary input at every day's parser. A parser should either parse it or say what's
+// wrong with it - anything else (a panic, running out of memory, taking forever) is a bug.
+//
+//     cargo +nightly fuzz run parse
+//
+// The first byte picks the day, so the fuzzer can learn each one's grammar separately.
+#![no_main]
+
+use advent_of_code::registry::DAYS;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, input)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return;
+    };
+    let _ = DAYS[selector as usize % DAYS.len()].parse(input);
+});
@@ -0,0 +1,130 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::format::serialize_millis;
+
+// What a day's part returns. Most answers are plain integers, but a few don't fit in an i64 and
+// the odd one isn't a number at all.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => Answer::BigInt(n as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Part {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Part, D::Error> {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(serde::de::Error::custom(format!(
+                "Part must be 1 or 2, not {}",
+                n
+            ))),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Part must be 1 or 2, not {}", s)),
+        }
+    }
+}
+
+// An answer along with the puzzle it's the answer to. This is the only thing that knows how to
+// print an answer, so every day's output looks the same.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PuzzleAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+}
+
+impl fmt::Display for PuzzleAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} part {}: {}", self.day, self.part, self.answer)
+    }
+}
+
+// An answer along with how long it took to work out (not counting parsing).
+#[derive(Debug, Clone, Serialize)]
+pub struct TimedAnswer {
+    #[serde(flatten)]
+    pub answer: PuzzleAnswer,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_millis")]
+    pub elapsed: Duration,
+}
@@ -0,0 +1,211 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::answer::Part;
+use crate::registry::Day;
+use crate::runner::{self, DayRun};
+
+// Running one day on a whole directory of inputs, e.g. everyone's puzzle inputs, to check that
+// our solutions work on more than just ours.
+
+// The inputs in a directory, in name order. Hidden files (.DS_Store and friends) and
+// subdirectories are left out.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// How a day got on with one of the inputs.
+#[derive(Serialize)]
+pub struct InputRun {
+    pub input: PathBuf,
+    #[serde(flatten)]
+    pub run: DayRun,
+}
+
+// Run the day on one input, giving up after `timeout`. Some days only work on inputs shaped like
+// ours and never finish on anyone else's.
+pub fn run_input(day: &'static Day, input: &Path, part: Option<Part>, timeout: Duration) -> DayRun {
+    runner::run_with_timeout(day, Some(&input.to_string_lossy()), part, timeout)
+}
+
+// Run the day on each input in turn (not in parallel, so the timings are comparable).
+pub fn run_inputs(
+    day: &'static Day,
+    inputs: &[PathBuf],
+    part: Option<Part>,
+    timeout: Duration,
+) -> Vec<InputRun> {
+    inputs
+        .iter()
+        .map(|input| InputRun {
+            input: input.clone(),
+            run: run_input(day, input, part, timeout),
+        })
+        .collect()
+}
+
+// A row for each input with its answers, how long each phase took and what went wrong, if
+// anything did. Columns are as wide as they need to be, since answers can be any length.
+pub fn table(runs: &[InputRun]) -> String {
+    let time = |time: Option<Duration>| time.map_or("-".to_string(), |t| format!("{:.2?}", t));
+    let mut rows = vec![[
+        "Input",
+        "Part 1",
+        "Part 2",
+        "Parse",
+        "Part 1 time",
+        "Part 2 time",
+        "Error",
+    ]
+    .map(String::from)];
+    for InputRun { input, run } in runs {
+        let answer = |part| {
+            run.answers
+                .iter()
+                .find(|answer| answer.answer.part == part)
+                .map_or("-".to_string(), |answer| answer.answer.answer.to_string())
+        };
+        let name = input.file_name().map_or(input.as_os_str(), |name| name);
+        rows.push([
+            name.to_string_lossy().into_owned(),
+            answer(Part::One),
+            answer(Part::Two),
+            time(run.phases.parse),
+            time(run.phases.part1),
+            time(run.phases.part2),
+            run.error.clone().unwrap_or_default(),
+        ]);
+    }
+    let mut widths = [0; 7];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut lines = rows
+        .iter()
+        .map(|row| {
+            // Names and answers on the left, times on the right, like the other tables.
+            let cells = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, (cell, width))| {
+                    if (3..6).contains(&i) {
+                        format!("{:>width$}", cell, width = width)
+                    } else {
+                        format!("{:<width$}", cell, width = width)
+                    }
+                });
+            cells
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>();
+    let failed = runs.iter().filter(|run| run.run.error.is_some()).count();
+    lines.push(String::new());
+    lines.push(format!("{} inputs, {} failed", runs.len(), failed));
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PhaseStats;
+    use crate::testing::{day_run, scratch_dir};
+
+    #[test]
+    fn test_input_files() {
+        let dir = scratch_dir("batch-files");
+        for name in ["bob.txt", "alice.txt", ".DS_Store"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        std::fs::create_dir(dir.join("old")).unwrap();
+        assert_eq!(
+            input_files(&dir).unwrap(),
+            vec![dir.join("alice.txt"), dir.join("bob.txt")]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_run_inputs() {
+        let dir = scratch_dir("batch-run");
+        std::fs::write(
+            dir.join("example.txt"),
+            include_str!("../tests/fixtures/2023/day01.txt"),
+        )
+        .unwrap();
+        let day = crate::registry::find(2023, 1).unwrap();
+        let inputs = [dir.join("example.txt"), dir.join("missing.txt")];
+        let runs = run_inputs(day, &inputs, Some(Part::One), Duration::from_secs(10));
+        assert_eq!(runs[0].run.answers.len(), 1);
+        assert_eq!(runs[0].run.answers[0].answer.answer.to_string(), "142");
+        assert!(runs[0].run.error.is_none());
+        assert!(runs[1].run.error.is_some());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_run_input_times_out() {
+        let dir = scratch_dir("batch-timeout");
+        let day = crate::registry::find(2023, 23).unwrap();
+        let input = day.generate(&mut fastrand::Rng::with_seed(1), Some(7));
+        std::fs::write(dir.join("big.txt"), input.unwrap()).unwrap();
+        let run = run_input(day, &dir.join("big.txt"), None, Duration::from_millis(1));
+        assert_eq!(run.error.as_deref(), Some("timed out after 1ms"));
+        assert!(run.answers.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_table() {
+        // Without a time for part 2, to check the table copes without one.
+        let run = |answers, error| DayRun {
+            phases: PhaseStats {
+                part1: Some(Duration::from_millis(5)),
+                ..PhaseStats::default()
+            },
+            ..day_run(20, answers, error)
+        };
+        let runs = [
+            InputRun {
+                input: PathBuf::from("inputs/ours.txt"),
+                run: run(&[(Part::One, "1"), (Part::Two, "22")], None),
+            },
+            InputRun {
+                input: PathBuf::from("inputs/alice.txt"),
+                run: run(&[(Part::One, "333")], Some("timed out after 1s")),
+            },
+        ];
+        let table = table(&runs);
+        let lines = table.lines().collect::<Vec<&str>>();
+        assert_eq!(
+            lines[0],
+            "Input      Part 1  Part 2  Parse  Part 1 time  Part 2 time  Error"
+        );
+        assert_eq!(
+            lines[1],
+            "ours.txt   1       22          -       5.00ms            -"
+        );
+        assert_eq!(
+            lines[2],
+            "alice.txt  333     -           -       5.00ms            -  timed out after 1s"
+        );
+        assert_eq!(lines[4], "2 inputs, 1 failed");
+    }
+}
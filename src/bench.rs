@@ -0,0 +1,496 @@
+use std::fmt;
+use std::hint::black_box;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::answer::Part;
+use crate::error::RunError;
+use crate::format::{deserialize_millis, serialize_millis};
+use crate::registry::Day;
+use crate::runner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Serialize for Phase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Phase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Phase, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "parse" => Ok(Phase::Parse),
+            "part 1" => Ok(Phase::Part1),
+            "part 2" => Ok(Phase::Part2),
+            other => Err(serde::de::Error::custom(format!(
+                "Phase must be parse, part 1 or part 2, not {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(
+        rename = "min_ms",
+        serialize_with = "serialize_millis",
+        deserialize_with = "deserialize_millis"
+    )]
+    pub min: Duration,
+    #[serde(
+        rename = "median_ms",
+        serialize_with = "serialize_millis",
+        deserialize_with = "deserialize_millis"
+    )]
+    pub median: Duration,
+    #[serde(
+        rename = "mean_ms",
+        serialize_with = "serialize_millis",
+        deserialize_with = "deserialize_millis"
+    )]
+    pub mean: Duration,
+    #[serde(
+        rename = "stddev_ms",
+        serialize_with = "serialize_millis",
+        deserialize_with = "deserialize_millis"
+    )]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PhaseTimings {
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub phases: Vec<PhaseTimings>,
+}
+
+// Run every phase of a day `iterations` times. Each iteration parses the input afresh so that
+// the parts always see the same thing they would in a normal run.
+// A part that can't solve the input stops the bench, since timing it would be timing a failure.
+pub fn bench(day: &Day, input: &str, iterations: NonZeroUsize) -> Result<DayTimings, RunError> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+    for _ in 0..iterations.get() {
+        let start = Instant::now();
+        let parsed_input = black_box(day.parse(input)?);
+        parse.push(start.elapsed());
+
+        let unsolved = |part, error| RunError::Unsolved {
+            day: day.day,
+            part,
+            error,
+        };
+        let start = Instant::now();
+        black_box(day.solution.run_part1(parsed_input.as_ref()))
+            .map_err(|error| unsolved(Part::One, error))?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        if let Some(answer) = black_box(day.solution.run_part2(parsed_input.as_ref())) {
+            answer.map_err(|error| unsolved(Part::Two, error))?;
+            part2.push(start.elapsed());
+        }
+    }
+
+    let mut phases = vec![
+        PhaseTimings {
+            phase: Phase::Parse,
+            stats: Stats::from_samples(&mut parse),
+        },
+        PhaseTimings {
+            phase: Phase::Part1,
+            stats: Stats::from_samples(&mut part1),
+        },
+    ];
+    if !part2.is_empty() {
+        phases.push(PhaseTimings {
+            phase: Phase::Part2,
+            stats: Stats::from_samples(&mut part2),
+        });
+    }
+    Ok(DayTimings {
+        year: day.year,
+        day: day.day,
+        phases,
+    })
+}
+
+// Like `bench`, but a day that panics fails like one that errors, so it doesn't take the days
+// after it (and the baseline) down with it.
+pub fn bench_catching(
+    day: &Day,
+    input: &str,
+    iterations: NonZeroUsize,
+) -> Result<DayTimings, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| bench(day, input, iterations))) {
+        Ok(timings) => timings.map_err(|error| error.to_string()),
+        Err(payload) => Err(format!("panicked: {}", runner::panic_message(payload))),
+    }
+}
+
+pub fn print_table(timings: &[DayTimings]) {
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Mean", "Stddev"
+    );
+    for day in timings {
+        for PhaseTimings { phase, stats } in &day.phases {
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+                day.day,
+                phase.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+            );
+        }
+    }
+}
+
+// Timings we've saved to compare later runs against. It's the same shape as `--format json`
+// output, so a saved run works as a baseline too.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub timings: Vec<DayTimings>,
+}
+
+#[derive(Debug)]
+pub struct BaselineError {
+    pub path: String,
+    pub error: String,
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Couldn't use baseline file {}: {}",
+            self.path, self.error
+        )
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl Baseline {
+    // Without `missing_ok`, there has to be a baseline to load, because there's nothing to
+    // compare against otherwise.
+    pub fn load(path: &str, missing_ok: bool) -> Result<Baseline, BaselineError> {
+        let baseline_error = |error: &dyn fmt::Display| BaselineError {
+            path: path.to_string(),
+            error: error.to_string(),
+        };
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|e| baseline_error(&e)),
+            Err(e) if missing_ok && e.kind() == std::io::ErrorKind::NotFound => {
+                Ok(Baseline::default())
+            }
+            Err(e) => Err(baseline_error(&e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), BaselineError> {
+        let baseline_error = |error: &dyn fmt::Display| BaselineError {
+            path: path.to_string(),
+            error: error.to_string(),
+        };
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| baseline_error(&e))?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| baseline_error(&e))?;
+        std::fs::write(path, json + "\n").map_err(|e| baseline_error(&e))
+    }
+
+    // Replace the timings for the days we've just benchmarked, keeping the rest, so benchmarking
+    // one day doesn't throw away the baseline for all the others.
+    pub fn update(&mut self, timings: Vec<DayTimings>) {
+        self.timings.retain(|old| {
+            !timings
+                .iter()
+                .any(|new| new.year == old.year && new.day == old.day)
+        });
+        self.timings.extend(timings);
+        self.timings
+            .sort_by_key(|timings| (timings.year, timings.day));
+    }
+
+    fn median(&self, year: u16, day: u8, phase: Phase) -> Option<Duration> {
+        self.timings
+            .iter()
+            .filter(|timings| timings.year == year && timings.day == day)
+            .flat_map(|timings| &timings.phases)
+            .find(|timings| timings.phase == phase)
+            .map(|timings| timings.stats.median)
+    }
+}
+
+#[derive(Serialize)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    #[serde(rename = "baseline_ms", serialize_with = "serialize_millis")]
+    pub baseline: Duration,
+    #[serde(rename = "median_ms", serialize_with = "serialize_millis")]
+    pub median: Duration,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    // How much slower (or, if negative, faster) we are than the baseline, as a percentage.
+    pub fn change(&self) -> f64 {
+        (self.median.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+// Compare the median of each phase with the baseline's. A phase has regressed if it's more than
+// `threshold` percent slower, and more than `min_delta` slower too: the quickest phases take a
+// few microseconds, and a bit of noise is a big percentage of that. Phases the baseline doesn't
+// have are left out.
+pub fn compare(
+    baseline: &Baseline,
+    timings: &[DayTimings],
+    threshold: f64,
+    min_delta: Duration,
+) -> Vec<Comparison> {
+    timings
+        .iter()
+        .flat_map(|day| {
+            day.phases.iter().filter_map(|timings| {
+                let baseline = baseline.median(day.year, day.day, timings.phase)?;
+                let median = timings.stats.median;
+                Some(Comparison {
+                    year: day.year,
+                    day: day.day,
+                    phase: timings.phase,
+                    baseline,
+                    median,
+                    regressed: median.as_secs_f64()
+                        > baseline.as_secs_f64() * (1.0 + threshold / 100.0)
+                        && median > baseline + min_delta,
+                })
+            })
+        })
+        .collect()
+}
+
+pub fn print_comparison(comparisons: &[Comparison], threshold: f64, min_delta: Duration) {
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>8}",
+        "Day", "Phase", "Baseline", "Median", "Change"
+    );
+    for comparison in comparisons {
+        println!(
+            "{:>3}  {:<6}  {:>10}  {:>10}  {:>+7.1}%{}",
+            comparison.day,
+            comparison.phase.to_string(),
+            format!("{:.2?}", comparison.baseline),
+            format!("{:.2?}", comparison.median),
+            comparison.change(),
+            if comparison.regressed {
+                "  REGRESSED"
+            } else {
+                ""
+            }
+        );
+    }
+    let regressed = comparisons
+        .iter()
+        .filter(|comparison| comparison.regressed)
+        .map(|comparison| format!("day {} {}", comparison.day, comparison.phase))
+        .collect::<Vec<String>>();
+    if regressed.is_empty() {
+        println!(
+            "Nothing is more than {}% (and {:.2?}) slower than the baseline",
+            threshold, min_delta
+        );
+    } else {
+        println!(
+            "More than {}% (and {:.2?}) slower than the baseline: {}",
+            threshold,
+            min_delta,
+            regressed.join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::error::{ParseError, SolveError};
+    use crate::solution::Solution;
+
+    fn day_timings(day: u8, medians: &[(Phase, u64)]) -> DayTimings {
+        DayTimings {
+            year: 2023,
+            day,
+            phases: medians
+                .iter()
+                .map(|(phase, median)| {
+                    let median = Duration::from_millis(*median);
+                    PhaseTimings {
+                        phase: *phase,
+                        stats: Stats {
+                            min: median,
+                            median,
+                            mean: median,
+                            stddev: Duration::ZERO,
+                        },
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = ();
+
+        fn parse(&self, _input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &()) -> Result<Answer, SolveError> {
+            panic!("oh no")
+        }
+    }
+
+    #[test]
+    fn test_bench_catching_panic() {
+        let day = Day {
+            year: 2023,
+            day: 1,
+            solution: &Panics,
+        };
+        let iterations = NonZeroUsize::new(1).unwrap();
+        assert_eq!(
+            bench_catching(&day, "", iterations).err(),
+            Some("panicked: oh no".to_string())
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline {
+            timings: vec![day_timings(3, &[(Phase::Parse, 1), (Phase::Part2, 250)])],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        let loaded: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            loaded.median(2023, 3, Phase::Part2),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(loaded.median(2023, 3, Phase::Part1), None);
+    }
+
+    #[test]
+    fn test_update() {
+        let mut baseline = Baseline {
+            timings: vec![
+                day_timings(5, &[(Phase::Part1, 10)]),
+                day_timings(2, &[(Phase::Part1, 10)]),
+            ],
+        };
+        baseline.update(vec![
+            day_timings(5, &[(Phase::Part1, 20)]),
+            day_timings(1, &[(Phase::Part1, 30)]),
+        ]);
+        let days = baseline
+            .timings
+            .iter()
+            .map(|timings| timings.day)
+            .collect::<Vec<u8>>();
+        assert_eq!(days, vec![1, 2, 5]);
+        assert_eq!(
+            baseline.median(2023, 5, Phase::Part1),
+            Some(Duration::from_millis(20))
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline {
+            timings: vec![day_timings(
+                7,
+                &[(Phase::Parse, 1), (Phase::Part1, 10), (Phase::Part2, 10)],
+            )],
+        };
+        let timings = [
+            day_timings(
+                7,
+                &[(Phase::Parse, 1), (Phase::Part1, 12), (Phase::Part2, 10)],
+            ),
+            day_timings(8, &[(Phase::Part1, 100)]),
+        ];
+        let min_delta = Duration::from_micros(100);
+        let comparisons = compare(&baseline, &timings, 10.0, min_delta);
+        assert_eq!(comparisons.len(), 3);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change() - 20.0).abs() < 1e-9);
+        assert!(!comparisons[2].regressed);
+        assert!(compare(&baseline, &timings, 25.0, min_delta)
+            .iter()
+            .all(|comparison| !comparison.regressed));
+        // Part 1 is 20% slower, but only by 2ms.
+        assert!(compare(&baseline, &timings, 10.0, Duration::from_millis(3))
+            .iter()
+            .all(|comparison| !comparison.regressed));
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use crate::answer::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Where the session token comes from if it isn't in the environment. It's the value of the
+// `session` cookie from a logged-in browser, so keep it out of git.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".aoc-session";
+
+// The AoC site asks automated tools to say who they are.
+const USER_AGENT: &str = "github.com/graemerobertson/aoc2023";
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    Http {
+        url: String,
+        error: String,
+    },
+    Status {
+        url: String,
+        code: u16,
+        body: String,
+    },
+    Io {
+        path: String,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "No session token: set {} or put it in {}",
+                SESSION_ENV_VAR, SESSION_FILE
+            ),
+            ClientError::Http { url, error } => write!(f, "Couldn't reach {}: {}", url, error),
+            ClientError::Status { url, code, body } => {
+                write!(f, "{} returned {}: {}", url, code, body.trim())
+            }
+            ClientError::Io { path, error } => write!(f, "Couldn't write {}: {}", path, error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+// The session token from the environment if it's set, otherwise from the first line of
+// `session_file`.
+pub fn session_token(session_file: &str) -> Result<String, ClientError> {
+    let token = match std::env::var(SESSION_ENV_VAR) {
+        Ok(token) => token,
+        Err(_) => std::fs::read_to_string(session_file).map_err(|_| ClientError::NoSession)?,
+    };
+    match token.trim() {
+        "" => Err(ClientError::NoSession),
+        token => Ok(token.to_string()),
+    }
+}
+
+// Talks to the AoC site (or anything pretending to be it, for testing) as a logged-in user.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    pub fn get_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(year, day));
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    // Post an answer and hand back the page the site replies with, for `submit` to make sense of.
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        read_response(&url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    let http_error = |error: &dyn fmt::Display| ClientError::Http {
+        url: url.to_string(),
+        error: error.to_string(),
+    };
+    match response {
+        Ok(response) => response.into_string().map_err(|e| http_error(&e)),
+        Err(ureq::Error::Status(code, response)) => Err(ClientError::Status {
+            url: url.to_string(),
+            code,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(error) => Err(http_error(&error)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+// Download a day's input to `path`, unless we've already got it. The data files are checked in
+// empty, so an empty file counts as not having it yet.
+pub fn fetch_input(
+    client: &Client,
+    year: u16,
+    day: u8,
+    path: &str,
+) -> Result<Fetched, ClientError> {
+    if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.get_input(year, day)?;
+    let io_error = |error| ClientError::Io {
+        path: path.to_string(),
+        error,
+    };
+    if let Some(parent) = Path::new(path).parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    std::fs::write(path, input).map_err(io_error)?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use crate::testing::scratch_dir;
+
+    // What the stand-in server saw of a request.
+    pub(crate) struct Request {
+        pub(crate) request_line: String,
+        pub(crate) headers: Vec<String>,
+        pub(crate) body: String,
+    }
+
+    // A stand-in for the AoC site that gives each connection the next of `responses` (a status
+    // code and body) and sends back what it was asked. Returns the base URL to point a client at.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (code, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = vec![];
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end().to_string();
+                    if header.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    headers.push(header);
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    code,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender
+                    .send(Request {
+                        request_line: request_line.trim_end().to_string(),
+                        headers,
+                        body: String::from_utf8(request_body).unwrap(),
+                    })
+                    .unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    #[test]
+    fn test_fetch_downloads_and_caches() {
+        let (base_url, requests) = serve(vec![(200, "1 2 3\n")]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = scratch_dir("client-fetch");
+        let path = dir.join("day07.txt").to_string_lossy().into_owned();
+
+        assert_eq!(
+            fetch_input(&client, 2022, 7, &path).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "GET /2022/day/7/input HTTP/1.1");
+        assert!(request
+            .headers
+            .iter()
+            .any(|h| h == "Cookie: session=abc123"));
+
+        // The server only answers once, so this would fail if it went back for the input.
+        assert_eq!(
+            fetch_input(&client, 2022, 7, &path).unwrap(),
+            Fetched::Cached
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_reports_bad_status() {
+        let (base_url, _requests) = serve(vec![(404, "Not unlocked yet")]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = scratch_dir("client-status");
+        let path = dir.join("day25.txt").to_string_lossy().into_owned();
+
+        match fetch_input(&client, 2022, 25, &path) {
+            Err(ClientError::Status {
+                code: 404, body, ..
+            }) => assert_eq!(body, "Not unlocked yet"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(!Path::new(&path).exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (base_url, requests) = serve(vec![(200, "<main>That's the right answer!</main>")]);
+        let client = Client::new(&base_url, "abc123");
+
+        let page = client.post_answer(2023, 4, Part::Two, "42").unwrap();
+        assert_eq!(page, "<main>That's the right answer!</main>");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "POST /2023/day/4/answer HTTP/1.1");
+        assert!(request
+            .headers
+            .iter()
+            .any(|h| h == "Cookie: session=abc123"));
+        assert_eq!(request.body, "level=2&answer=42");
+    }
+}
@@ -0,0 +1,251 @@
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::generate;
+use crate::registry;
+use crate::runner::panic_message;
+use crate::solution::Solution;
+use crate::year2023::{day05, day19, day23};
+
+// Some days have two ways of getting to the same answer: the slow, obviously right one and the
+// clever one (or the one we actually use and a rewrite of it). Running both on lots of made up
+// inputs and checking they agree finds the cases our own input doesn't cover.
+
+// What one strategy came up with: its answer, or why it didn't get one.
+pub type Outcome = Result<Answer, String>;
+
+// Two strategies for the same answer on one day.
+pub struct Pair {
+    pub year: u16,
+    pub day: u8,
+    pub what: &'static str,
+    pub strategies: [&'static str; 2],
+    // Both strategies' outcomes for an input, or None if it doesn't parse.
+    pub run: fn(&str) -> Option<[Outcome; 2]>,
+    // The input sizes to try, smallest first. The slow strategies can't cope with inputs the
+    // size of the real ones.
+    pub sizes: RangeInclusive<usize>,
+}
+
+// A strategy's answer, with no answer or a panic as a failure.
+fn catching<T: Into<Answer>>(strategy: impl FnOnce() -> Result<T, String>) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(strategy)) {
+        Ok(Ok(answer)) => Ok(answer.into()),
+        Ok(Err(error)) => Err(error),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload))),
+    }
+}
+
+fn no_answer() -> String {
+    "no answer".to_string()
+}
+
+fn day05_part2(input: &str) -> Option<[Outcome; 2]> {
+    let almanac = day05::Day05.parse(input).ok()?;
+    Some([
+        catching(|| {
+            day05::Day05
+                .part2(&almanac)
+                .ok_or_else(no_answer)?
+                .map_err(|error| error.to_string())
+        }),
+        catching(|| day05::lowest_location_forwards(&almanac).ok_or_else(no_answer)),
+    ])
+}
+
+fn day19_part1(input: &str) -> Option<[Outcome; 2]> {
+    let system = day19::Day19.parse(input).ok()?;
+    Some([
+        catching(|| {
+            day19::Day19
+                .part1(&system)
+                .map_err(|error| error.to_string())
+        }),
+        catching(|| Ok(day19::accepted_ratings_by_ranges(&system))),
+    ])
+}
+
+fn day23_part1(input: &str) -> Option<[Outcome; 2]> {
+    let trails = day23::Day23.parse(input).ok()?;
+    Some([
+        catching(|| {
+            day23::Day23
+                .part1(&trails)
+                .map_err(|error| error.to_string())
+        }),
+        catching(|| day23::longest_hike_via_junctions(&trails).ok_or_else(no_answer)),
+    ])
+}
+
+pub const PAIRS: &[Pair] = &[
+    Pair {
+        year: 2023,
+        day: 5,
+        what: "part 2",
+        strategies: ["reverse search", "forward ranges"],
+        run: day05_part2,
+        sizes: 1..=30,
+    },
+    Pair {
+        year: 2023,
+        day: 19,
+        what: "part 1",
+        strategies: ["simulation", "range counting"],
+        run: day19_part1,
+        sizes: 1..=100,
+    },
+    Pair {
+        year: 2023,
+        day: 23,
+        what: "part 1",
+        strategies: ["brute force", "junction graph"],
+        run: day23_part1,
+        sizes: 2..=6,
+    },
+];
+
+pub fn pairs(year: u16, day: Option<u8>) -> Vec<&'static Pair> {
+    PAIRS
+        .iter()
+        .filter(|pair| pair.year == year && day.is_none_or(|day| pair.day == day))
+        .collect()
+}
+
+// An input the strategies disagree on: where it came from, what each said, and the smallest bit
+// of it that they still disagree on.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub outcomes: [Outcome; 2],
+    pub minimised: String,
+    pub minimised_outcomes: [Outcome; 2],
+}
+
+// Whether the strategies disagree on an input in the same way as they did on `original`: both
+// answering (differently), or the same one failing. Cutting an input down tends to break it, and
+// we don't want to swap the disagreement we found for some other one along the way.
+fn disagrees_like(outcomes: &[Outcome; 2], original: &[Outcome; 2]) -> bool {
+    outcomes[0] != outcomes[1]
+        && outcomes[0].is_ok() == original[0].is_ok()
+        && outcomes[1].is_ok() == original[1].is_ok()
+}
+
+// Cut an input the strategies disagree on down to the fewest lines they still disagree on in the
+// same way. Try taking out big chunks first, then smaller ones, down to single lines, until
+// there's nothing left we can take out.
+pub fn minimise(pair: &Pair, input: &str, outcomes: &[Outcome; 2]) -> (String, [Outcome; 2]) {
+    let mut lines = input.lines().collect::<Vec<&str>>();
+    let mut best = (input.to_string(), outcomes.clone());
+    let mut chunk = (lines.len() / 2).max(1);
+    loop {
+        let mut removed_any = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let candidate = [&lines[..i], &lines[(i + chunk).min(lines.len())..]].concat();
+            let text = generate::join_lines(candidate.iter().map(|line| line.to_string()));
+            match (pair.run)(&text) {
+                Some(candidate_outcomes) if disagrees_like(&candidate_outcomes, outcomes) => {
+                    lines = candidate;
+                    best = (text, candidate_outcomes);
+                    removed_any = true;
+                }
+                _ => i += chunk,
+            }
+        }
+        if !removed_any {
+            if chunk == 1 {
+                return best;
+            }
+            chunk /= 2;
+        }
+    }
+}
+
+// Run both strategies on `runs` generated inputs, working up through the pair's sizes, and
+// return how many they agreed on, or the first one they didn't. Run i uses seed `seed + i`, so
+// `generate` can make the same input again.
+pub fn check(pair: &Pair, runs: usize, seed: u64) -> Result<usize, Box<Disagreement>> {
+    let day = registry::find(pair.year, pair.day).expect("pair for a day that doesn't exist");
+    let (smallest, largest) = (*pair.sizes.start(), *pair.sizes.end());
+    let mut agreed = 0;
+    for run in 0..runs {
+        let seed = seed.wrapping_add(run as u64);
+        let size = smallest + (largest - smallest) * run / runs.max(2).saturating_sub(1);
+        let Some(input) = day.generate(&mut Rng::with_seed(seed), Some(size)) else {
+            continue;
+        };
+        let Some(outcomes) = (pair.run)(&input) else {
+            continue;
+        };
+        if outcomes[0] != outcomes[1] {
+            let (minimised, minimised_outcomes) = minimise(pair, &input, &outcomes);
+            return Err(Box::new(Disagreement {
+                seed,
+                size,
+                input,
+                outcomes,
+                minimised,
+                minimised_outcomes,
+            }));
+        }
+        agreed += 1;
+    }
+    Ok(agreed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Strategies that disagree whenever there's a line saying "bad" and agree otherwise.
+    fn fussy(input: &str) -> Option<[Outcome; 2]> {
+        let bad = input.lines().any(|line| line == "bad");
+        Some([Ok(Answer::Int(1)), Ok(Answer::Int(if bad { 2 } else { 1 }))])
+    }
+
+    #[test]
+    fn test_minimise() {
+        let pair = Pair {
+            year: 2023,
+            day: 1,
+            what: "part 1",
+            strategies: ["one", "other"],
+            run: fussy,
+            sizes: 1..=1,
+        };
+        let input = "a\nb\nc\nbad\nd\ne\nf\ng\nh\n";
+        let outcomes = fussy(input).unwrap();
+        let (minimised, minimised_outcomes) = minimise(&pair, input, &outcomes);
+        assert_eq!(minimised, "bad\n");
+        assert_eq!(minimised_outcomes, outcomes);
+    }
+
+    #[test]
+    fn test_disagrees_like() {
+        let (one, two) = (Ok(Answer::Int(1)), Ok(Answer::Int(2)));
+        let failed = Err("panicked: oops".to_string());
+        let original = [one.clone(), two.clone()];
+        assert!(disagrees_like(&[two.clone(), one.clone()], &original));
+        assert!(!disagrees_like(&[one.clone(), one.clone()], &original));
+        assert!(!disagrees_like(&[one.clone(), failed.clone()], &original));
+        assert!(disagrees_like(&[two, failed.clone()], &[one, failed]));
+    }
+
+    // A handful of small inputs for each pair, to catch them drifting apart.
+    #[test]
+    fn test_pairs_agree() {
+        for pair in PAIRS {
+            if let Err(disagreement) = check(pair, 3, 0) {
+                panic!(
+                    "day {} {} disagrees on\n{}",
+                    pair.day, pair.what, disagreement.minimised
+                );
+            }
+        }
+    }
+}
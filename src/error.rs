@@ -0,0 +1,189 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use crate::answer::Part;
+
+// Where a day's input stopped making sense, and why. Lines and columns count from 1. Parsers
+// don't know which day they are, so `day` is filled in by the registry on the way out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // For when there's nothing on the line to point at, e.g. a section that never turned up.
+    pub fn missing(line: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: 0,
+            line,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} input, line {} column {}: {} (at {:?})",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// A part that couldn't get an answer out of an input that parsed fine, usually because the input
+// isn't shaped the way the part relies on ours being.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> SolveError {
+        SolveError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+// A line of puzzle input that knows where it is, so that parsers can point at the bit of it
+// that's wrong without doing any arithmetic themselves.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+// The lines of a grid, checking that there's at least one and that they're all the same width.
+pub fn grid(input: &str) -> Result<Vec<Line<'_>>, ParseError> {
+    let lines = lines(input).collect::<Vec<Line>>();
+    let width = match lines.first() {
+        Some(first) if !first.text.is_empty() => first.text.chars().count(),
+        _ => return Err(ParseError::missing(1, "expected a grid")),
+    };
+    for line in &lines {
+        let line_width = line.text.chars().count();
+        if line_width != width {
+            return Err(line.error_at(
+                line_width.min(width),
+                format!("expected every line to be {} characters wide", width),
+            ));
+        }
+    }
+    Ok(lines)
+}
+
+impl<'a> Line<'a> {
+    // An error about `token`, which should be a slice of this line. If it isn't, the error just
+    // points at the start of the line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+        ParseError {
+            day: 0,
+            line: self.number,
+            column: self.text.get(..offset).map_or(0, |s| s.chars().count()) + 1,
+            text: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    // An error about the character at `index` (counting chars from 0), for grid inputs.
+    pub fn error_at(&self, index: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: 0,
+            line: self.number,
+            column: index + 1,
+            text: self
+                .text
+                .chars()
+                .nth(index)
+                .map(String::from)
+                .unwrap_or_default(),
+            message: message.into(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str, what: &str) -> Result<T, ParseError> {
+        token
+            .trim()
+            .parse()
+            .map_err(|_| self.error(token, format!("expected {}", what)))
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(separator)
+            .ok_or_else(|| self.error(text, format!("expected {:?}", separator)))
+    }
+}
+
+// Anything that can stop a day from running before it gets to an answer.
+#[derive(Debug)]
+pub enum RunError {
+    Input {
+        path: String,
+        error: io::Error,
+    },
+    Parse(ParseError),
+    // A file of our own (rather than a puzzle input) that isn't what we expect.
+    Malformed {
+        path: String,
+        error: String,
+    },
+    Unsolved {
+        day: u8,
+        part: Part,
+        error: SolveError,
+    },
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Input { path, error } => write!(f, "Couldn't read {}: {}", path, error),
+            RunError::Parse(error) => write!(f, "{}", error),
+            RunError::Malformed { path, error } => write!(f, "Couldn't parse {}: {}", path, error),
+            RunError::Unsolved { day, part, error } => {
+                write!(f, "Day {} part {} has no answer: {}", day, part, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> RunError {
+        RunError::Parse(error)
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+// Bits and pieces for days' `generate`, which make up random inputs shaped like the real ones.
+
+// Puzzle inputs are lines, each ending in a newline.
+pub fn join_lines(lines: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+    lines
+        .into_iter()
+        .map(|line| format!("{}\n", line.as_ref()))
+        .collect()
+}
+
+// `count` different names made of characters from `alphabet`, none of them in `taken`. Names are
+// at least `len` characters long, and longer if there aren't enough short ones to go round.
+pub fn names(
+    rng: &mut Rng,
+    count: usize,
+    len: usize,
+    alphabet: &str,
+    taken: &[&str],
+) -> Vec<String> {
+    let alphabet = alphabet.chars().collect::<Vec<char>>();
+    let mut len = len.max(1);
+    while alphabet
+        .len()
+        .checked_pow(len as u32)
+        .is_some_and(|possible| possible < 2 * (count + taken.len()))
+    {
+        len += 1;
+    }
+    let mut seen = taken
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<HashSet<String>>();
+    let mut names = vec![];
+    while names.len() < count {
+        let name = (0..len)
+            .map(|_| alphabet[rng.usize(..alphabet.len())])
+            .collect::<String>();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+// A random loop through the points of a `height` by `width` grid (as (row, column)) that never
+// touches or crosses itself, in order round the loop. The first point is always a corner.
+//
+// It's the outline of a random tree of up to `cells` cells, drawn on a grid a third of the size.
+// Each cell is a 2x2 block with a gap between blocks that aren't joined, so there are points
+// inside the loop as well as on it. Outlines of trees can't have holes in them, and the gaps stop
+// two bits of the outline from meeting at a point, so walking round it gives a simple loop.
+pub fn random_loop(
+    rng: &mut Rng,
+    height: usize,
+    width: usize,
+    cells: usize,
+) -> Vec<(usize, usize)> {
+    let (rows, cols) = ((height / 3).max(1) as isize, (width / 3).max(1) as isize);
+    let neighbours = |(row, col): (isize, isize)| {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .map(move |(dr, dc)| (row + dr, col + dc))
+            .filter(|&(row, col)| row >= 0 && row < rows && col >= 0 && col < cols)
+    };
+
+    // Grow the tree, with each cell's block at three times its coordinates on the fine grid and
+    // the gap between a pair of them filled in if they're joined.
+    let start = (rng.isize(0..rows), rng.isize(0..cols));
+    let mut tree = HashSet::from([start]);
+    let mut filled = HashSet::new();
+    let mut fill = |(row, col): (isize, isize)| {
+        filled.extend([
+            (row, col),
+            (row + 1, col),
+            (row, col + 1),
+            (row + 1, col + 1),
+        ])
+    };
+    fill((3 * start.0, 3 * start.1));
+    let mut frontier = neighbours(start)
+        .map(|next| (start, next))
+        .collect::<Vec<_>>();
+    while tree.len() < cells.max(1) && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.usize(..frontier.len()));
+        if !tree.insert(to) {
+            continue;
+        }
+        fill((3 * to.0, 3 * to.1));
+        fill((3 * (from.0 + to.0) / 2, 3 * (from.1 + to.1) / 2));
+        frontier.extend(
+            neighbours(to)
+                .filter(|next| !tree.contains(next))
+                .map(|next| (to, next)),
+        );
+    }
+
+    // Point (row, col) is the top left corner of fine cell (row, col). A step from one point to
+    // the next is part of the outline if the cells either side of it aren't both in or both out.
+    let steps = |(row, col): (isize, isize)| {
+        [
+            ((-1, 0), (row - 1, col - 1), (row - 1, col)),
+            ((1, 0), (row, col - 1), (row, col)),
+            ((0, -1), (row - 1, col - 1), (row, col - 1)),
+            ((0, 1), (row - 1, col), (row, col)),
+        ]
+        .into_iter()
+        .filter(|(_, a, b)| filled.contains(a) != filled.contains(b))
+        .map(move |((dr, dc), _, _)| (row + dr, col + dc))
+    };
+    let start = (3 * start.0, 3 * start.1);
+    let mut outline = vec![];
+    let mut previous = None;
+    let mut point = start;
+    loop {
+        outline.push((point.0 as usize, point.1 as usize));
+        let next = steps(point)
+            .find(|next| Some(*next) != previous)
+            .expect("outline has a dead end");
+        previous = Some(point);
+        point = next;
+        if point == start {
+            return outline;
+        }
+    }
+}
+
+// The first prime at or after `n`, for days that want periods that don't share any factors.
+pub fn next_prime(n: u64) -> u64 {
+    let is_prime = |n: u64| {
+        n >= 2
+            && (2..)
+                .take_while(|d| d * d <= n)
+                .all(|d| !n.is_multiple_of(d))
+    };
+    (n..).find(|n| is_prime(*n)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::DAYS;
+
+    #[test]
+    fn test_random_loop() {
+        let mut rng = Rng::with_seed(7);
+        for cells in [1, 2, 10, 50] {
+            let outline = random_loop(&mut rng, 20, 30, cells);
+            let points = outline.iter().collect::<HashSet<_>>();
+            assert_eq!(points.len(), outline.len());
+            for (i, a) in outline.iter().enumerate() {
+                let b = outline[(i + 1) % outline.len()];
+                assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+                assert!(a.0 < 20 && a.1 < 30);
+            }
+        }
+    }
+
+    #[test]
+    fn test_names() {
+        let mut rng = Rng::with_seed(7);
+        let names = names(&mut rng, 30, 1, "ab", &["aaaa"]);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 30);
+        assert!(names.iter().all(|name| name.len() == 6));
+    }
+
+    // Whatever a day makes up has to be something it can parse.
+    #[test]
+    fn test_generated_inputs_parse() {
+        for day in DAYS {
+            for seed in 0..3 {
+                let mut rng = Rng::with_seed(seed);
+                if let Some(input) = day.generate(&mut rng, Some(10)) {
+                    if let Err(error) = day.parse(&input) {
+                        panic!("{}\n{}", error, input);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Building blocks for generating puzzle inputs in tests. Each day's tests describe its input
+// grammar with these, check that anything matching the grammar parses, and then check that
+// `mutated` versions of it (which mostly won't match) come back as a `ParseError` rather than a
+// panic.
+use proptest::prelude::*;
+use proptest::sample::{select, Index, SizeRange};
+use proptest::test_runner::TestCaseError;
+
+use crate::solution::Solution;
+
+// A string of characters drawn from `alphabet`.
+pub fn chars(alphabet: &str, len: impl Into<SizeRange>) -> impl Strategy<Value = String> {
+    let alphabet = alphabet.chars().collect::<Vec<char>>();
+    prop::collection::vec(select(alphabet), len).prop_map(|chars| chars.into_iter().collect())
+}
+
+// The rows of a rectangular grid of characters drawn from `alphabet`.
+pub fn grid(
+    alphabet: &str,
+    rows: impl Into<SizeRange>,
+    cols: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<String>> {
+    let alphabet = alphabet.to_string();
+    let (rows, cols) = (rows.into(), cols.into());
+    (cols.start()..=cols.end_incl())
+        .prop_flat_map(move |width| prop::collection::vec(chars(&alphabet, width), rows.clone()))
+}
+
+// A grid with exactly one `c` in it somewhere, e.g. a start point.
+pub fn grid_with_one(
+    alphabet: &str,
+    c: char,
+    rows: impl Into<SizeRange>,
+    cols: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<String>> {
+    (grid(alphabet, rows, cols), any::<Index>()).prop_map(move |(mut grid, index)| {
+        let width = grid[0].chars().count();
+        let at = index.index(grid.len() * width);
+        let row = &mut grid[at / width];
+        *row = row
+            .chars()
+            .enumerate()
+            .map(|(col, old)| if col == at % width { c } else { old })
+            .collect();
+        grid
+    })
+}
+
+// Numbers (or anything else) with `separator` between them.
+pub fn join<T: ToString>(items: &[T], separator: &str) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+// Small ways of breaking an input. Positions are picked when the mutation is applied, since
+// they depend on how long the input is by then.
+#[derive(Debug, Clone)]
+enum Mutation {
+    Insert(Index, char),
+    Replace(Index, char),
+    Delete(Index),
+    Truncate(Index),
+}
+
+// Characters that mean something to at least one parser are much more likely to get past the
+// first check than arbitrary ones, so favour them.
+fn interesting_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        3 => select("0123456789-+,:;=@~|#.SAR{}()<>%&xmas \n".chars().collect::<Vec<char>>()),
+        1 => any::<char>(),
+    ]
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        (any::<Index>(), interesting_char()).prop_map(|(at, c)| Mutation::Insert(at, c)),
+        (any::<Index>(), interesting_char()).prop_map(|(at, c)| Mutation::Replace(at, c)),
+        any::<Index>().prop_map(Mutation::Delete),
+        any::<Index>().prop_map(Mutation::Truncate),
+    ]
+}
+
+// A valid input with a few mutations applied.
+pub fn mutated(valid: impl Strategy<Value = String>) -> impl Strategy<Value = String> {
+    (valid, prop::collection::vec(mutation(), 1..4)).prop_map(|(input, mutations)| {
+        let mut chars = input.chars().collect::<Vec<char>>();
+        for mutation in mutations {
+            match mutation {
+                Mutation::Insert(at, c) => chars.insert(at.index(chars.len() + 1), c),
+                Mutation::Replace(at, c) if !chars.is_empty() => {
+                    let at = at.index(chars.len());
+                    chars[at] = c;
+                }
+                Mutation::Delete(at) if !chars.is_empty() => {
+                    chars.remove(at.index(chars.len()));
+                }
+                Mutation::Truncate(at) => chars.truncate(at.index(chars.len() + 1)),
+                _ => (),
+            }
+        }
+        chars.into_iter().collect()
+    })
+}
+
+// For use in `proptest!`: fail the case (with the parser's complaint) if `input` doesn't parse.
+pub fn parses<S: Solution>(day: &S, input: &str) -> Result<(), TestCaseError> {
+    day.parse(input)
+        .map(|_| ())
+        .map_err(|error| TestCaseError::fail(error.to_string()))
+}
+
+mod tests {
+    use super::*;
+    use crate::registry::DAYS;
+
+    proptest! {
+        // Whatever they're given, parsers should give up with an error rather than panic. The
+        // fuzz targets in fuzz/ do the same with a lot more inputs.
+        #[test]
+        fn test_any_input_never_panics(day in 0..DAYS.len(), input in ".*") {
+            let _ = DAYS[day].parse(&input);
+        }
+
+        #[test]
+        fn test_grid_with_one(grid in grid_with_one(".#", 'S', 1..5, 1..5)) {
+            prop_assert_eq!(grid.concat().matches('S').count(), 1);
+        }
+    }
+}
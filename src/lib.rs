@@ -0,0 +1,25 @@
+// Every day's solution, a module per year, plus the machinery for running, timing and checking
+// them. The binary in main.rs is just a command line on top of this.
+pub mod answer;
+pub mod batch;
+pub mod bench;
+pub mod client;
+pub mod differential;
+pub mod error;
+pub mod format;
+pub mod generate;
+#[cfg(test)]
+mod grammar;
+pub mod logging;
+pub mod memory;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+#[cfg(test)]
+mod testing;
+pub mod verify;
+pub mod watch;
+pub mod year2023;
@@ -0,0 +1,106 @@
+use log::kv::{self, Key, Value, VisitSource};
+use log::{LevelFilter, Log, Metadata, Record};
+
+// Days say what they're up to with the `log` macros, putting anything worth looking at in
+// key-values rather than the message, e.g.
+//
+//   log::debug!(press = press, low = low, high = high; "pulses sent");
+//
+// None of it shows unless asked for with -v (debug) or -vv (trace as well). Other crates only get
+// to log warnings, or a verbose run would be mostly ureq.
+
+const CRATE: &str = env!("CARGO_CRATE_NAME");
+
+struct Logger {
+    level: LevelFilter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let level = if metadata.target().starts_with(CRATE) {
+            self.level
+        } else {
+            LevelFilter::Warn
+        };
+        metadata.level() <= level
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}", format(record));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+struct Pairs<'a>(&'a mut String);
+
+impl<'kvs> VisitSource<'kvs> for Pairs<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        self.0.push_str(&format!(" {}={}", key, value));
+        Ok(())
+    }
+}
+
+// One line per event, like `DEBUG year2023::day14: platform repeats first=3 again=10`.
+pub fn format(record: &Record) -> String {
+    let target = record
+        .target()
+        .strip_prefix(CRATE)
+        .map_or(record.target(), |target| target.trim_start_matches("::"));
+    let mut line = format!("{} {}: {}", record.level(), target, record.args());
+    // Writing to a string can't fail.
+    let _ = record.key_values().visit(&mut Pairs(&mut line));
+    line
+}
+
+// How much to show for the number of -v flags given.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    if log::set_boxed_logger(Box::new(Logger { level })).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let pairs: &[(&str, usize)] = &[("first", 3), ("again", 10)];
+        let record = Record::builder()
+            .level(log::Level::Debug)
+            .target("advent_of_code::year2023::day14")
+            .args(format_args!("platform repeats"))
+            .key_values(&pairs)
+            .build();
+        assert_eq!(
+            format(&record),
+            "DEBUG year2023::day14: platform repeats first=3 again=10"
+        );
+    }
+
+    #[test]
+    fn test_enabled() {
+        let logger = Logger {
+            level: LevelFilter::Debug,
+        };
+        let metadata = |level, target| Metadata::builder().level(level).target(target).build();
+        assert!(logger.enabled(&metadata(
+            log::Level::Debug,
+            "advent_of_code::year2023::day17"
+        )));
+        assert!(!logger.enabled(&metadata(
+            log::Level::Trace,
+            "advent_of_code::year2023::day17"
+        )));
+        assert!(!logger.enabled(&metadata(log::Level::Debug, "ureq::unit")));
+        assert!(logger.enabled(&metadata(log::Level::Warn, "ureq::unit")));
+    }
+}
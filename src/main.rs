@@ -1,90 +1,833 @@
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
 use structopt::StructOpt;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+
+use advent_of_code::answer::{Part, TimedAnswer};
+use advent_of_code::format::{self, Format};
+use advent_of_code::registry::{self, Day};
+use advent_of_code::{
+    batch, bench, client, differential, logging, memory, report, runner, scaffold, submit, verify,
+    watch,
+};
 
 #[derive(StructOpt)]
 struct Cli {
-    day: u16,
+    day: Option<u8>,
+
+    /// Which year's puzzles to use. Defaults to 2023.
+    #[structopt(long, global = true)]
+    year: Option<u16>,
+
+    /// Show what the days get up to as they run: -v for debug events, -vv for everything
+    #[structopt(short, long, parse(from_occurrences), global = true)]
+    verbose: u8,
+
+    /// Report peak heap use and allocations for each phase. Needs a build with `--features mem`.
+    #[structopt(long, global = true)]
+    mem: bool,
+
+    /// Read the puzzle input from this file instead of data/<year>/dayNN.txt. Use `-` for stdin.
+    #[structopt(long, global = true)]
+    input: Option<String>,
+
+    /// Only run this part (1 or 2). Both parts are run by default.
+    #[structopt(long, global = true)]
+    part: Option<Part>,
+
+    /// How to print the results: text or json
+    #[structopt(long, default_value = "text", global = true)]
+    format: Format,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(StructOpt)]
+enum Command {
+    /// Time the parse, part 1 and part 2 phases of a day, or of every day if none is given
+    Bench {
+        day: Option<u8>,
+
+        /// How many times to run each day (at least once)
+        #[structopt(long, short, default_value = "10")]
+        iterations: NonZeroUsize,
+
+        /// Save these timings to the baseline file, replacing any it has for the same days
+        #[structopt(long)]
+        save: bool,
+
+        /// Compare these timings with the baseline file, and exit with an error if any phase's
+        /// median is more than --threshold percent and --min-delta milliseconds slower
+        #[structopt(long)]
+        compare: bool,
+
+        /// Where to save and compare timings
+        #[structopt(long, default_value = "data/bench_baseline.json")]
+        baseline: String,
+
+        /// How much slower (in percent) than the baseline a phase can get before it's flagged
+        #[structopt(long, default_value = "10")]
+        threshold: f64,
+
+        /// How much slower (in milliseconds) than the baseline a phase has to get before it's
+        /// flagged, whatever the percentage. The quickest phases are mostly noise.
+        #[structopt(long, default_value = "0.1", parse(try_from_str = parse_millis))]
+        min_delta: Duration,
+    },
+    /// Run every day at once across a pool of threads, then summarise how long each took
+    All {
+        /// How many days to run at once. Defaults to the number of CPUs.
+        #[structopt(long, short)]
+        threads: Option<usize>,
+
+        /// Give up on a day after this many seconds
+        #[structopt(long, default_value = "60")]
+        timeout: u64,
+    },
+    /// Check a day's answers (or every day's) against the known-good answers file
+    Verify {
+        day: Option<u8>,
+
+        /// The known-good answers to check against
+        #[structopt(long, default_value = "data/answers.json")]
+        answers: String,
+    },
+    /// Download a day's puzzle input into data/<year>/dayNN.txt, unless we've already got it. The
+    /// session token is read from AOC_SESSION or .aoc-session.
+    Fetch {
+        day: u8,
+
+        /// The site to download from
+        #[structopt(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Submit an answer for one part of a day, working it out first if it isn't given. Answers
+    /// we already know are wrong are never sent again.
+    Submit {
+        day: u8,
+
+        // Named apart from the global --part, which clap won't let it share a name with.
+        #[structopt(name = "submit-part", value_name = "part")]
+        part: Part,
+
+        answer: Option<String>,
+
+        /// The site to submit to
+        #[structopt(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Where to keep track of what we've submitted and what the site said
+        #[structopt(long, default_value = "data/submissions.json")]
+        history: String,
+    },
+    /// Start a new day: generate its module from a template, register it, and create an empty
+    /// data file and example fixture for it
+    New { day: u8 },
+    /// Rerun a day on its input and examples whenever they change (or the binary is rebuilt),
+    /// showing how the answers moved
+    Watch {
+        day: u8,
+
+        /// How often to check for changes, in milliseconds
+        #[structopt(long, default_value = "500")]
+        interval: u64,
+
+        /// Give up on a run after this many seconds
+        #[structopt(long, default_value = "10")]
+        timeout: u64,
+    },
+    /// Run a day on every file in a directory of inputs (e.g. teammates' puzzle inputs) and
+    /// tabulate the answers, errors and timings for each, to check the solution isn't tuned to ours
+    Run {
+        day: u8,
+
+        /// The directory of puzzle inputs to run the day on
+        #[structopt(long)]
+        inputs: String,
+
+        /// Give up on an input after this many seconds
+        #[structopt(long, default_value = "60")]
+        timeout: u64,
+    },
+    /// Run every day and write a markdown table of their answers, timings and notes
+    Report {
+        /// Show a hash of each answer instead of the answer itself
+        #[structopt(long)]
+        redact: bool,
+
+        /// Put the table into this file (e.g. README.md) between `<!-- report -->` and
+        /// `<!-- /report -->`, replacing the first such table, instead of printing it
+        #[structopt(long)]
+        output: Option<String>,
+    },
+    /// Make up a random input for a day, shaped like the real ones, and print it. Handy for
+    /// seeing how a day scales and for finding edge cases our own input doesn't have.
+    Generate {
+        day: u8,
+
+        /// How big an input to make. What this counts depends on the day (lines, grid size, ...);
+        /// each day defaults to something about the size of its real input.
+        #[structopt(long)]
+        size: Option<usize>,
+
+        /// Seed for the random numbers, to make the same input again. A random one is used (and
+        /// printed to stderr) by default.
+        #[structopt(long)]
+        seed: Option<u64>,
+    },
+    /// Check the days with two ways of working out an answer (a brute force and a cleverer one)
+    /// by running both on random inputs, and show the smallest input they disagree on, if any
+    Differential {
+        day: Option<u8>,
+
+        /// How many inputs to try for each day, working up from small ones
+        #[structopt(long, default_value = "20")]
+        runs: usize,
+
+        /// Seed for the first input's random numbers; each input after it adds one. A random one
+        /// is used (and printed to stderr) by default.
+        #[structopt(long)]
+        seed: Option<u64>,
+    },
+}
+
+// A duration given in (possibly fractional) milliseconds, e.g. "0.1".
+fn parse_millis(millis: &str) -> Result<Duration, String> {
+    let millis = millis.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(millis / 1000.0)
+        .map_err(|_| "expected a number of milliseconds, at least 0".to_string())
+}
+
+#[derive(Serialize)]
+struct RunReport {
+    answers: Vec<TimedAnswer>,
+    #[serde(flatten)]
+    phases: runner::PhaseStats,
+}
+
+#[derive(Serialize)]
+struct BenchReport<'a> {
+    timings: &'a [bench::DayTimings],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comparison: Option<&'a [bench::Comparison]>,
+}
+
+#[derive(Serialize)]
+struct InputsReport {
+    answers: Vec<InputAnswer>,
+    runs: Vec<batch::InputRun>,
+}
+
+#[derive(Serialize)]
+struct InputAnswer {
+    input: std::path::PathBuf,
+    #[serde(flatten)]
+    answer: TimedAnswer,
+}
+
+#[derive(Serialize)]
+struct AllReport {
+    answers: Vec<TimedAnswer>,
+    days: Vec<runner::DayRun>,
+    #[serde(rename = "total_ms", serialize_with = "format::serialize_millis")]
+    total: Duration,
+    #[serde(rename = "wall_clock_ms", serialize_with = "format::serialize_millis")]
+    wall_clock: Duration,
+}
+
+fn run_day(day: &Day, input_path: Option<&str>, part: Option<Part>, format: Format, mem: bool) {
+    let mut answers = vec![];
+    let mut report = |answer: TimedAnswer| match format {
+        Format::Text => println!("{}", answer.answer),
+        Format::Json => answers.push(answer),
+    };
+    let mut phases = match runner::run(day, input_path, part, &mut report) {
+        Ok(phases) => phases,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+    if !mem {
+        phases.memory = runner::PhaseMemory::default();
+    }
+    match format {
+        Format::Text => {
+            println!("Timings: {}", phases);
+            if mem {
+                println!("Memory: {}", phases.memory);
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&RunReport { answers, phases }).unwrap()
+        ),
+    }
+}
+
+fn run_all(
+    year: u16,
+    part: Option<Part>,
+    threads: usize,
+    timeout: Duration,
+    format: Format,
+    mem: bool,
+) {
+    let days = find_days(year);
+    let start = Instant::now();
+    let mut runs = runner::run_all(&days, part, threads, timeout);
+    let wall_clock = start.elapsed();
+    if !mem {
+        for run in &mut runs {
+            run.phases.memory = runner::PhaseMemory::default();
+        }
+    }
+    let total = runs.iter().map(|run| run.elapsed).sum::<Duration>();
+    let failed = runs.iter().any(|run| run.error.is_some());
+
+    match format {
+        Format::Text => {
+            for run in &runs {
+                for answer in &run.answers {
+                    println!("{}", answer.answer);
+                }
+                if let Some(error) = &run.error {
+                    println!("Day {} failed: {}", run.day, error);
+                }
+            }
+            println!();
+            println!(
+                "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+                "Day", "Parse", "Part 1", "Part 2", "Total"
+            );
+            let time =
+                |time: Option<Duration>| time.map_or("-".to_string(), |t| format!("{:.2?}", t));
+            for run in &runs {
+                println!(
+                    "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+                    run.day,
+                    time(run.phases.parse),
+                    time(run.phases.part1),
+                    time(run.phases.part2),
+                    time(Some(run.elapsed))
+                );
+            }
+            println!(
+                "Total {:.2?} across {} days ({:.2?} wall clock on {} threads)",
+                total,
+                runs.len(),
+                wall_clock,
+                threads
+            );
+            if mem {
+                print_memory_table(&runs);
+            }
+        }
+        Format::Json => {
+            let answers = runs
+                .iter_mut()
+                .flat_map(|run| std::mem::take(&mut run.answers))
+                .collect();
+            let report = AllReport {
+                answers,
+                days: runs,
+                total,
+                wall_clock,
+            };
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn print_memory_table(runs: &[runner::DayRun]) {
+    println!();
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+        "Day", "Phase", "Peak", "Allocations", "Allocated"
+    );
+    for run in runs {
+        let memory = run.phases.memory;
+        for (phase, stats) in [
+            ("parse", memory.parse),
+            ("part 1", memory.part1),
+            ("part 2", memory.part2),
+        ] {
+            if let Some(stats) = stats {
+                println!(
+                    "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                    run.day,
+                    phase,
+                    memory::format_bytes(stats.peak_bytes),
+                    stats.allocations,
+                    memory::format_bytes(stats.allocated_bytes)
+                );
+            }
+        }
+    }
+}
+
+fn fetch(day: &Day, base_url: &str) -> Result<(), client::ClientError> {
+    let session = client::session_token(client::SESSION_FILE)?;
+    let client = client::Client::new(base_url, &session);
+    let path = day.input_path();
+    match client::fetch_input(&client, day.year, day.day, &path)? {
+        client::Fetched::Cached => println!("Already got {}", path),
+        client::Fetched::Downloaded => println!("Downloaded {}", path),
+    }
+    Ok(())
+}
+
+fn submit(
+    day: &Day,
+    input_path: Option<&str>,
+    part: Part,
+    answer: Option<String>,
+    base_url: &str,
+    history: &str,
+) -> Result<submit::Response, Box<dyn std::error::Error>> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let mut computed = None;
+            runner::run(day, input_path, Some(part), &mut |answer: TimedAnswer| {
+                computed = Some(answer.answer.answer.to_string())
+            })?;
+            computed.ok_or_else(|| format!("Day {} has no part {}", day.day, part))?
+        }
+    };
+    println!("Submitting day {} part {}: {}", day.day, part, answer);
+    let session = client::session_token(client::SESSION_FILE)?;
+    let client = client::Client::new(base_url, &session);
+    Ok(submit::submit(
+        &client, history, day.year, day.day, part, &answer,
+    )?)
+}
+
+// The first global flag given that the subcommand would ignore, and the subcommand's name. Better
+// to refuse than to quietly do something other than what was asked. Bench, run and all check the
+// flags they can't use themselves, since they can say why.
+fn ignored_flag(args: &Cli) -> Option<(&'static str, &'static str)> {
+    let input = args.input.is_some().then_some("--input");
+    let part = args.part.is_some().then_some("--part");
+    let format = (args.format == Format::Json).then_some("--format json");
+    let (command, ignored) = match args.command.as_ref()? {
+        Command::Bench { .. } | Command::All { .. } | Command::Run { .. } => return None,
+        Command::Submit { .. } => ("submit", [None, part, format]),
+        Command::Verify { .. } => ("verify", [input, part, format]),
+        Command::Fetch { .. } => ("fetch", [input, part, format]),
+        Command::New { .. } => ("new", [input, part, format]),
+        Command::Watch { .. } => ("watch", [input, part, format]),
+        Command::Report { .. } => ("report", [input, part, format]),
+        Command::Generate { .. } => ("generate", [input, part, format]),
+        Command::Differential { .. } => ("differential", [input, part, format]),
+    };
+    ignored
+        .into_iter()
+        .flatten()
+        .next()
+        .map(|flag| (flag, command))
+}
+
+fn find_day(year: u16, day: u8) -> &'static Day {
+    registry::find(year, day).unwrap_or_else(|| {
+        eprintln!("Unimplemented day: {} of {}", day, year);
+        std::process::exit(1);
+    })
+}
+
+fn find_days(year: u16) -> Vec<&'static Day> {
+    let found = registry::days(year);
+    if found.is_empty() {
+        eprintln!("No days for {}", year);
+        std::process::exit(1);
+    }
+    found
 }
 
 fn main() {
     let args = Cli::from_args();
-    match args.day {
-        1 => day01::day01(),
-        2 => day02::day02(),
-        3 => day03::day03(),
-        4 => day04::day04(),
-        5 => day05::day05(),
-        6 => day06::day06(),
-        7 => day07::day07(),
-        8 => day08::day08(),
-        9 => day09::day09(),
-        10 => day10::day10(),
-        11 => day11::day11(),
-        12 => day12::day12(),
-        13 => day13::day13(),
-        14 => day14::day14(),
-        15 => day15::day15(),
-        16 => day16::day16(),
-        17 => day17::day17(),
-        18 => day18::day18(),
-        19 => day19::day19(),
-        20 => day20::day20(),
-        21 => day21::day21(),
-        22 => day22::day22(),
-        23 => day23::day23(),
-        24 => day24::day24(),
-        25 => day25::day25(),
-        411 => {
-            day01::day01();
-            day02::day02();
-            day03::day03();
-            day04::day04();
-            day05::day05();
-            day06::day06();
-            day07::day07();
-            day08::day08();
-            day09::day09();
-            day10::day10();
-            day11::day11();
-            day12::day12();
-            day13::day13();
-            day14::day14();
-            day15::day15();
-            day16::day16();
-            day17::day17();
-            day18::day18();
-            day19::day19();
-            day20::day20();
-            day21::day21();
-            day22::day22();
-            day23::day23();
-            day24::day24();
-            day25::day25();
-        }
-        _ => println!("Unimplemented day: {}", args.day),
+    let year = args.year.unwrap_or(registry::DEFAULT_YEAR);
+    logging::init(args.verbose);
+    if args.mem && !memory::ENABLED {
+        eprintln!("--mem needs the counting allocator: rebuild with `--features mem`");
+        std::process::exit(1);
+    }
+    if let Some((flag, command)) = ignored_flag(&args) {
+        eprintln!("{} can't be used with {}", flag, command);
+        std::process::exit(1);
+    }
+    match args.command {
+        Some(Command::Bench {
+            day,
+            iterations,
+            save,
+            compare,
+            baseline,
+            threshold,
+            min_delta,
+        }) => {
+            let (input, format) = (args.input, args.format);
+            if args.part.is_some() {
+                eprintln!("--part can't be used with bench, which always times both parts");
+                std::process::exit(1);
+            }
+            let days: Vec<&Day> = match day {
+                Some(day) => vec![find_day(year, day)],
+                None if input.is_some() => {
+                    eprintln!("--input can only be used with a single day");
+                    std::process::exit(1);
+                }
+                None => find_days(year),
+            };
+            // Load the baseline first, so we don't spend ages benchmarking only to find it's
+            // missing.
+            let saved = match bench::Baseline::load(&baseline, !compare) {
+                Ok(saved) => saved,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            };
+            // A day that fails is left out of the timings (and the baseline), but the others
+            // still get benchmarked, compared and saved.
+            let mut failed = false;
+            let timings = days
+                .into_iter()
+                .filter_map(|day| {
+                    let timings = runner::read_input(day, input.as_deref())
+                        .map_err(|error| error.to_string())
+                        .and_then(|input| bench::bench_catching(day, &input, iterations));
+                    if let Err(error) = &timings {
+                        eprintln!("Day {} failed: {}", day.day, error);
+                        failed = true;
+                    }
+                    timings.ok()
+                })
+                .collect::<Vec<_>>();
+            let comparison =
+                compare.then(|| bench::compare(&saved, &timings, threshold, min_delta));
+            let regressed = comparison
+                .iter()
+                .flatten()
+                .any(|comparison| comparison.regressed);
+            match format {
+                Format::Text => {
+                    bench::print_table(&timings);
+                    if let Some(comparison) = &comparison {
+                        println!();
+                        bench::print_comparison(comparison, threshold, min_delta);
+                    }
+                }
+                Format::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&BenchReport {
+                        timings: &timings,
+                        comparison: comparison.as_deref(),
+                    })
+                    .unwrap()
+                ),
+            }
+            if save {
+                let mut saved = saved;
+                saved.update(timings);
+                if let Err(error) = saved.save(&baseline) {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            }
+            if regressed || failed {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Verify { day, answers }) => {
+            let days: Vec<&Day> = match day {
+                Some(day) => vec![find_day(year, day)],
+                None => find_days(year),
+            };
+            let expected = match verify::ExpectedAnswers::load(&answers) {
+                Ok(expected) => expected,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            };
+            if !verify::verify_days(&days, &expected) {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Fetch { day, base_url }) => {
+            let day = find_day(year, day);
+            if let Err(error) = fetch(day, &base_url) {
+                eprintln!("{}", error);
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+            history,
+        }) => {
+            let day = find_day(year, day);
+            match submit(
+                day,
+                args.input.as_deref(),
+                part,
+                answer,
+                &base_url,
+                &history,
+            ) {
+                Ok(submit::Response::Correct) => println!("Correct!"),
+                Ok(response) => {
+                    println!("Not accepted: {}", response);
+                    std::process::exit(1);
+                }
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Some(Command::New { day }) => match scaffold::new_day(Path::new("."), year, day) {
+            Ok(changed) => {
+                for path in changed {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(1);
+            }
+        },
+        Some(Command::Watch {
+            day,
+            interval,
+            timeout,
+        }) => {
+            let day = find_day(year, day);
+            let exe = std::env::current_exe().unwrap();
+            watch::watch(
+                &exe,
+                day,
+                Path::new("tests/fixtures"),
+                Duration::from_millis(interval),
+                Duration::from_secs(timeout),
+            );
+        }
+        Some(Command::Run {
+            day,
+            inputs,
+            timeout,
+        }) => {
+            if args.input.is_some() {
+                eprintln!("--input can't be used with run, which reads its inputs from --inputs");
+                std::process::exit(1);
+            }
+            let (part, format) = (args.part, args.format);
+            let day = find_day(year, day);
+            let files = match batch::input_files(Path::new(&inputs)) {
+                Ok(files) => files,
+                Err(error) => {
+                    eprintln!("Couldn't read {}: {}", inputs, error);
+                    std::process::exit(1);
+                }
+            };
+            let mut runs = batch::run_inputs(day, &files, part, Duration::from_secs(timeout));
+            let failed = runs.iter().any(|run| run.run.error.is_some());
+            match format {
+                Format::Text => print!("{}", batch::table(&runs)),
+                Format::Json => {
+                    let answers = runs
+                        .iter_mut()
+                        .flat_map(|run| {
+                            let input = run.input.clone();
+                            std::mem::take(&mut run.run.answers)
+                                .into_iter()
+                                .map(move |answer| InputAnswer {
+                                    input: input.clone(),
+                                    answer,
+                                })
+                        })
+                        .collect();
+                    let report = InputsReport { answers, runs };
+                    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Report { redact, output }) => {
+            let days = find_days(year);
+            let table = report::report(&days, redact);
+            match output {
+                Some(path) => {
+                    let result = std::fs::read_to_string(&path)
+                        .or_else(|error| match error.kind() {
+                            std::io::ErrorKind::NotFound => Ok(String::new()),
+                            _ => Err(error),
+                        })
+                        .and_then(|document| {
+                            std::fs::write(&path, report::splice(&document, &table))
+                        });
+                    match result {
+                        Ok(()) => println!("Wrote the report to {}", path),
+                        Err(error) => {
+                            eprintln!("Couldn't update {}: {}", path, error);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                None => print!("{}", table),
+            }
+        }
+        Some(Command::Generate { day, size, seed }) => {
+            let day = find_day(year, day);
+            let seed = seed.unwrap_or_else(|| fastrand::u64(..));
+            eprintln!("Seed {}", seed);
+            match day.generate(&mut fastrand::Rng::with_seed(seed), size) {
+                Some(input) => print!("{}", input),
+                None => {
+                    eprintln!("Day {} has no input generator", day.day);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Some(Command::Differential { day, runs, seed }) => {
+            let pairs = differential::pairs(year, day);
+            if pairs.is_empty() {
+                eprintln!("Nothing to check against each other");
+                std::process::exit(1);
+            }
+            let seed = seed.unwrap_or_else(|| fastrand::u64(..));
+            eprintln!("Seed {}", seed);
+            // A strategy that panics is reported as failing, with the panic's message, but the
+            // panic still gets printed to stderr as it happens (cutting an input down can set off
+            // quite a few), since it might not be the strategy's fault.
+            let mut disagreed = false;
+            for pair in pairs {
+                let label = format!(
+                    "Day {} {}, {} vs {}",
+                    pair.day, pair.what, pair.strategies[0], pair.strategies[1]
+                );
+                let outcome = |outcome: &differential::Outcome| match outcome {
+                    Ok(answer) => answer.to_string(),
+                    Err(error) => error.clone(),
+                };
+                match differential::check(pair, runs, seed) {
+                    Ok(agreed) => println!("{}: agreed on {} inputs", label, agreed),
+                    Err(disagreement) => {
+                        disagreed = true;
+                        println!(
+                            "{}: disagreed on the input from `generate {} --year {} --seed {} --size {}`",
+                            label, pair.day, pair.year, disagreement.seed, disagreement.size
+                        );
+                        for (strategy, answer) in pair.strategies.iter().zip(&disagreement.outcomes)
+                        {
+                            println!("  {}: {}", strategy, outcome(answer));
+                        }
+                        println!(
+                            "Cut down to {} lines, on which",
+                            disagreement.minimised.lines().count()
+                        );
+                        for (strategy, answer) in
+                            pair.strategies.iter().zip(&disagreement.minimised_outcomes)
+                        {
+                            println!("  {}: {}", strategy, outcome(answer));
+                        }
+                        print!("{}", disagreement.minimised);
+                    }
+                }
+            }
+            if disagreed {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::All { threads, timeout }) => {
+            if args.input.is_some() {
+                eprintln!("--input can only be used with a single day");
+                std::process::exit(1);
+            }
+            let threads =
+                threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+            run_all(
+                year,
+                args.part,
+                threads,
+                Duration::from_secs(timeout),
+                args.format,
+                args.mem,
+            );
+        }
+        None => match args.day {
+            Some(day) => run_day(
+                find_day(year, day),
+                args.input.as_deref(),
+                args.part,
+                args.format,
+                args.mem,
+            ),
+            None => {
+                Cli::clap().print_help().unwrap();
+                println!();
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Clap only checks that argument names are unique in debug builds, when it builds the
+    // parser, so make sure every subcommand gets built.
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &str| Cli::from_iter_safe(args.split_whitespace()).map(|cli| cli.part);
+        assert_eq!(parse("aoc 1 --part 2").unwrap(), Some(Part::Two));
+        assert_eq!(parse("aoc submit 1 2").unwrap(), None);
+        assert!(parse("aoc bench --compare --min-delta -1").is_err());
+        for command in [
+            "bench",
+            "all",
+            "verify",
+            "fetch 1",
+            "new 1",
+            "watch 1",
+            "run 1 --inputs x",
+            "report",
+            "generate 1",
+            "differential",
+        ] {
+            assert!(
+                parse(&format!("aoc {} --format json", command)).is_ok(),
+                "{}",
+                command
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignored_flag() {
+        let ignored = |args: &str| ignored_flag(&Cli::from_iter(args.split_whitespace()));
+        assert_eq!(ignored("aoc 1 --part 2 --input x --format json"), None);
+        assert_eq!(ignored("aoc submit 1 2 --input x"), None);
+        assert_eq!(
+            ignored("aoc submit 1 2 --part 1"),
+            Some(("--part", "submit"))
+        );
+        assert_eq!(ignored("aoc bench --format json"), None);
+        for command in ["verify", "report", "generate 1", "differential"] {
+            assert!(ignored(&format!("aoc {} --format json", command)).is_some());
+            assert!(ignored(&format!("aoc {} --input x", command)).is_some());
+        }
     }
 }
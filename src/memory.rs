@@ -0,0 +1,180 @@
+use std::fmt;
+
+use serde::Serialize;
+
+// Whether this build counts allocations. Counting means swapping in our own global allocator,
+// which costs a little on every allocation, so it's behind the `mem` feature.
+pub const ENABLED: bool = cfg!(feature = "mem");
+
+// What a stretch of code did to the heap, on the thread that ran it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemStats {
+    // The most that was live at once, over and above what was live at the start.
+    pub peak_bytes: usize,
+    // Reallocations count, since growing a Vec or HashMap is exactly what we want to see.
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+}
+
+impl fmt::Display for MemStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} peak, {} allocations ({} in all)",
+            format_bytes(self.peak_bytes),
+            self.allocations,
+            format_bytes(self.allocated_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.2} {}", size, UNITS[unit]),
+    }
+}
+
+// Run `f`, counting what it allocates. Without the `mem` feature there's nothing to count, so
+// this just runs it.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemStats>) {
+    #[cfg(feature = "mem")]
+    {
+        let start = counting::start();
+        let result = f();
+        (result, Some(counting::since(start)))
+    }
+    #[cfg(not(feature = "mem"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "mem")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::MemStats;
+
+    // Counters are per thread so that `all` can measure days running side by side. Memory freed
+    // on a different thread from the one that allocated it makes `CURRENT` drift, which is why
+    // it's signed, but none of the days do that.
+    thread_local! {
+        static CURRENT: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<isize> = const { Cell::new(0) };
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        static ALLOCATED_BYTES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // The allocator can be called while a thread's locals are being torn down, when they can't
+    // be touched any more, so those allocations just go uncounted.
+    fn record_alloc(size: usize) {
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + size as isize);
+            PEAK.with(|peak| peak.set(peak.get().max(current.get())));
+            ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+            ALLOCATED_BYTES.with(|allocated| allocated.set(allocated.get() + size));
+        });
+    }
+
+    fn record_dealloc(size: usize) {
+        let _ = CURRENT.try_with(|current| current.set(current.get() - size as isize));
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub(super) struct Start {
+        current: isize,
+        allocations: usize,
+        allocated_bytes: usize,
+    }
+
+    pub(super) fn start() -> Start {
+        let current = CURRENT.with(Cell::get);
+        PEAK.with(|peak| peak.set(current));
+        Start {
+            current,
+            allocations: ALLOCATIONS.with(Cell::get),
+            allocated_bytes: ALLOCATED_BYTES.with(Cell::get),
+        }
+    }
+
+    pub(super) fn since(start: Start) -> MemStats {
+        MemStats {
+            peak_bytes: (PEAK.with(Cell::get) - start.current).max(0) as usize,
+            allocations: ALLOCATIONS.with(Cell::get) - start.allocations,
+            allocated_bytes: ALLOCATED_BYTES.with(Cell::get) - start.allocated_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[cfg(feature = "mem")]
+    #[test]
+    fn test_measure() {
+        let (total, stats) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(1000);
+            v.extend(0..1000);
+            let w = v.clone();
+            w.iter().sum::<u64>()
+        });
+        assert_eq!(total, 499500);
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.allocated_bytes, 16000);
+        assert_eq!(stats.peak_bytes, 16000);
+    }
+}
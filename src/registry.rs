@@ -0,0 +1,80 @@
+use std::any::Any;
+
+use fastrand::Rng;
+
+use crate::error::ParseError;
+use crate::solution::Runner;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn Runner,
+}
+
+impl Day {
+    const fn new(year: u16, day: u8, solution: &'static dyn Runner) -> Day {
+        Day {
+            year,
+            day,
+            solution,
+        }
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("data/{}/day{:02}.txt", self.year, self.day)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        self.solution
+            .parse_input(input)
+            .map_err(|error| ParseError {
+                day: self.day,
+                ..error
+            })
+    }
+
+    pub fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        self.solution.generate_input(rng, size)
+    }
+}
+
+// The year we mean when nobody says otherwise.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+// Every day we've got a solution for, in every year. Add new days here (in order) and everything
+// else picks them up. `new` adds lines to this list, so keep them one per line.
+pub static DAYS: &[Day] = &[
+    Day::new(2023, 1, &crate::year2023::day01::Day01),
+    Day::new(2023, 2, &crate::year2023::day02::Day02),
+    Day::new(2023, 3, &crate::year2023::day03::Day03),
+    Day::new(2023, 4, &crate::year2023::day04::Day04),
+    Day::new(2023, 5, &crate::year2023::day05::Day05),
+    Day::new(2023, 6, &crate::year2023::day06::Day06),
+    Day::new(2023, 7, &crate::year2023::day07::Day07),
+    Day::new(2023, 8, &crate::year2023::day08::Day08),
+    Day::new(2023, 9, &crate::year2023::day09::Day09),
+    Day::new(2023, 10, &crate::year2023::day10::Day10),
+    Day::new(2023, 11, &crate::year2023::day11::Day11),
+    Day::new(2023, 12, &crate::year2023::day12::Day12),
+    Day::new(2023, 13, &crate::year2023::day13::Day13),
+    Day::new(2023, 14, &crate::year2023::day14::Day14),
+    Day::new(2023, 15, &crate::year2023::day15::Day15),
+    Day::new(2023, 16, &crate::year2023::day16::Day16),
+    Day::new(2023, 17, &crate::year2023::day17::Day17),
+    Day::new(2023, 18, &crate::year2023::day18::Day18),
+    Day::new(2023, 19, &crate::year2023::day19::Day19),
+    Day::new(2023, 20, &crate::year2023::day20::Day20),
+    Day::new(2023, 21, &crate::year2023::day21::Day21),
+    Day::new(2023, 22, &crate::year2023::day22::Day22),
+    Day::new(2023, 23, &crate::year2023::day23::Day23),
+    Day::new(2023, 24, &crate::year2023::day24::Day24),
+    Day::new(2023, 25, &crate::year2023::day25::Day25),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn days(year: u16) -> Vec<&'static Day> {
+    DAYS.iter().filter(|d| d.year == year).collect()
+}
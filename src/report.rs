@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+use crate::answer::Part;
+use crate::registry::Day;
+use crate::runner::{self, DayRun};
+
+// The report goes between these in the README, so rerunning it replaces the old one and leaves
+// everything else alone.
+pub const START_MARKER: &str = "<!-- report -->";
+pub const END_MARKER: &str = "<!-- /report -->";
+
+// Parts that take longer than this are shown in bold, so the slow days stand out.
+const SLOW: Duration = Duration::from_secs(1);
+
+// A short hash of an answer, for showing that we've got one (and spotting when it changes)
+// without giving it away. Short answers could be found by hashing every number in turn, so this
+// only hides them from a casual glance.
+pub fn redact(answer: &str) -> String {
+    Sha256::digest(answer.as_bytes())[..4]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// Pipes would end the table cell early, and newlines the whole row.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn time(time: Option<Duration>) -> String {
+    match time {
+        Some(time) if time >= SLOW => format!("**{:.2?}**", time),
+        Some(time) => format!("{:.2?}", time),
+        None => "-".to_string(),
+    }
+}
+
+// A markdown table with a row for each day: its answers (or their hashes), how long each part
+// took, and how we solved it. A day that failed shows what went wrong in place of the first
+// answer it didn't get to.
+pub fn markdown(days: &[&Day], runs: &[DayRun], redacted: bool) -> String {
+    let mut lines = vec![
+        "| Day | Title | Part 1 | Part 2 | Part 1 time | Part 2 time | Notes |".to_string(),
+        "| --: | ----- | ------ | ------ | ----------: | ----------: | ----- |".to_string(),
+    ];
+    for (day, run) in days.iter().zip(runs) {
+        let mut error = run
+            .error
+            .as_deref()
+            .map(|error| format!("failed: {}", error));
+        let mut answer = |part| {
+            let found = run.answers.iter().find(|answer| answer.answer.part == part);
+            match found.map(|answer| answer.answer.answer.to_string()) {
+                Some(answer) if redacted => format!("`{}`", redact(&answer)),
+                Some(answer) => answer,
+                None => error.take().unwrap_or_else(|| "-".to_string()),
+            }
+        };
+        let (part1, part2) = (answer(Part::One), answer(Part::Two));
+        let title = match day.solution.title() {
+            "" => "-".to_string(),
+            title => format!(
+                "[{}](https://adventofcode.com/{}/day/{})",
+                title, day.year, day.day
+            ),
+        };
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            day.day,
+            cell(&title),
+            cell(&part1),
+            cell(&part2),
+            time(run.phases.part1),
+            time(run.phases.part2),
+            cell(day.solution.notes())
+        ));
+    }
+    let total = runs.iter().map(|run| run.elapsed).sum::<Duration>();
+    lines.push(String::new());
+    lines.push(format!(
+        "{} days in {:.2?}, parsing included.",
+        runs.len(),
+        total
+    ));
+    lines.join("\n") + "\n"
+}
+
+// Run each day in turn (not in parallel, so they don't slow each other down) and report on them.
+pub fn report(days: &[&Day], redacted: bool) -> String {
+    let runs = days
+        .iter()
+        .map(|day| runner::run_catching(day, None, None))
+        .collect::<Vec<DayRun>>();
+    markdown(days, &runs, redacted)
+}
+
+// Put the report into a document between the markers, replacing whatever was there. If the
+// markers aren't there yet, the report (and the markers) go on the end.
+pub fn splice(document: &str, report: &str) -> String {
+    let replaced = document.find(START_MARKER).and_then(|start| {
+        let end = start + document[start..].find(END_MARKER)?;
+        Some(format!(
+            "{}{}\n{}{}",
+            &document[..start],
+            START_MARKER,
+            report,
+            &document[end..]
+        ))
+    });
+    replaced.unwrap_or_else(|| {
+        let mut document = document.to_string();
+        if !document.is_empty() && !document.ends_with('\n') {
+            document.push('\n');
+        }
+        format!("{}\n{}\n{}{}\n", document, START_MARKER, report, END_MARKER)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::testing::day_run;
+
+    #[test]
+    fn test_redact() {
+        assert_eq!(redact("142"), "d4ee9f58");
+        assert_ne!(redact("142"), redact("143"));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let days = [
+            registry::find(2023, 1).unwrap(),
+            registry::find(2023, 2).unwrap(),
+        ];
+        let runs = [
+            day_run(1, &[(Part::One, "142"), (Part::Two, "a|b")], None),
+            day_run(2, &[(Part::One, "8")], Some("panicked: oops")),
+        ];
+        let table = markdown(&days, &runs, false);
+        let lines = table.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[2].starts_with(
+            "| 1 | [Trebuchet?!](https://adventofcode.com/2023/day/1) | 142 | a\\|b | 5.00ms | **1.50s** |"
+        ));
+        assert!(lines[3].contains("| 8 | failed: panicked: oops |"));
+        assert_eq!(lines[5], "2 days in 4.00s, parsing included.");
+
+        let table = markdown(&days, &runs, true);
+        assert!(table.contains("| `d4ee9f58` |"));
+        assert!(!table.contains("142"));
+    }
+
+    #[test]
+    fn test_splice() {
+        let spliced = splice("# aoc2023", "new\n");
+        assert_eq!(
+            spliced,
+            "# aoc2023\n\n<!-- report -->\nnew\n<!-- /report -->\n"
+        );
+        assert_eq!(
+            splice(&format!("{}More\n", spliced), "newer\n"),
+            "# aoc2023\n\n<!-- report -->\nnewer\n<!-- /report -->\nMore\n"
+        );
+    }
+}
@@ -0,0 +1,335 @@
+use std::any::Any;
+use std::fmt;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::answer::{Part, PuzzleAnswer, TimedAnswer};
+use crate::error::RunError;
+use crate::format::{serialize_millis, serialize_optional_millis};
+use crate::memory::{self, MemStats};
+use crate::registry::Day;
+
+pub fn read_input(day: &Day, path: Option<&str>) -> Result<String, RunError> {
+    let path = path.map_or_else(|| day.input_path(), String::from);
+    let result = if path == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        std::fs::read_to_string(&path)
+    };
+    result.map_err(|error| RunError::Input { path, error })
+}
+
+// How long each phase of a run took, and what it allocated if we're counting. Phases that weren't
+// run (or that the day doesn't have) are None.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct PhaseStats {
+    #[serde(
+        rename = "parse_ms",
+        serialize_with = "serialize_optional_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parse: Option<Duration>,
+    #[serde(
+        rename = "part1_ms",
+        serialize_with = "serialize_optional_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part1: Option<Duration>,
+    #[serde(
+        rename = "part2_ms",
+        serialize_with = "serialize_optional_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part2: Option<Duration>,
+    #[serde(skip_serializing_if = "PhaseMemory::is_empty")]
+    pub memory: PhaseMemory,
+}
+
+// Only filled in when built with the `mem` feature.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct PhaseMemory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse: Option<MemStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<MemStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<MemStats>,
+}
+
+impl PhaseMemory {
+    pub fn is_empty(&self) -> bool {
+        self.parse.is_none() && self.part1.is_none() && self.part2.is_none()
+    }
+}
+
+impl fmt::Display for PhaseMemory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let phases = [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(phase, stats)| Some(format!("{} {}", phase, stats?)))
+        .collect::<Vec<String>>();
+        write!(f, "{}", phases.join("; "))
+    }
+}
+
+impl fmt::Display for PhaseStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let phases = [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(phase, time)| Some(format!("{} {:.2?}", phase, time?)))
+        .collect::<Vec<String>>();
+        write!(f, "{}", phases.join(", "))
+    }
+}
+
+// Parse the day's input and work out each requested part, handing the answers to `report` as
+// soon as they're ready. Returns how long each phase took (and what it allocated).
+pub fn run(
+    day: &Day,
+    input_path: Option<&str>,
+    part: Option<Part>,
+    report: &mut dyn FnMut(TimedAnswer),
+) -> Result<PhaseStats, RunError> {
+    let input = read_input(day, input_path)?;
+    let start = Instant::now();
+    let (parsed_input, parse_memory) = memory::measure(|| day.parse(&input));
+    let mut stats = PhaseStats {
+        parse: Some(start.elapsed()),
+        ..PhaseStats::default()
+    };
+    stats.memory.parse = parse_memory;
+    let parsed_input = parsed_input?;
+    if part != Some(Part::Two) {
+        let start = Instant::now();
+        let (answer, part1_memory) =
+            memory::measure(|| day.solution.run_part1(parsed_input.as_ref()));
+        let elapsed = start.elapsed();
+        stats.part1 = Some(elapsed);
+        stats.memory.part1 = part1_memory;
+        let answer = answer.map_err(|error| RunError::Unsolved {
+            day: day.day,
+            part: Part::One,
+            error,
+        })?;
+        report(TimedAnswer {
+            answer: PuzzleAnswer {
+                year: day.year,
+                day: day.day,
+                part: Part::One,
+                answer,
+            },
+            elapsed,
+        });
+    }
+    if part != Some(Part::One) {
+        let start = Instant::now();
+        let (answer, part2_memory) =
+            memory::measure(|| day.solution.run_part2(parsed_input.as_ref()));
+        if let Some(answer) = answer {
+            let elapsed = start.elapsed();
+            stats.part2 = Some(elapsed);
+            stats.memory.part2 = part2_memory;
+            let answer = answer.map_err(|error| RunError::Unsolved {
+                day: day.day,
+                part: Part::Two,
+                error,
+            })?;
+            report(TimedAnswer {
+                answer: PuzzleAnswer {
+                    year: day.year,
+                    day: day.day,
+                    part: Part::Two,
+                    answer,
+                },
+                elapsed,
+            });
+        }
+    }
+    Ok(stats)
+}
+
+// Everything that came out of running one day: the answers it managed, what went wrong if
+// anything did, and how long the whole thing (parsing included) took.
+#[derive(Serialize)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    #[serde(skip)]
+    pub answers: Vec<TimedAnswer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(flatten)]
+    pub phases: PhaseStats,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_millis")]
+    pub elapsed: Duration,
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+// Like `run`, but a day that fails or panics is reported rather than taking everything else down
+// with it.
+pub fn run_catching(day: &Day, input_path: Option<&str>, part: Option<Part>) -> DayRun {
+    run_catching_reporting(day, input_path, part, &mut |_| ())
+}
+
+// Like `run_catching`, but each answer is also handed to `report` as soon as it's ready, for
+// callers that might give up waiting before the run is done.
+pub fn run_catching_reporting(
+    day: &Day,
+    input_path: Option<&str>,
+    part: Option<Part>,
+    report: &mut dyn FnMut(&TimedAnswer),
+) -> DayRun {
+    let start = Instant::now();
+    let mut answers = vec![];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        run(day, input_path, part, &mut |answer| {
+            report(&answer);
+            answers.push(answer)
+        })
+    }));
+    let elapsed = start.elapsed();
+    // We lose the parse time along with everything else on the way out of an error or a panic,
+    // but any part that finished is still in its answer.
+    let part_time = |part| {
+        answers
+            .iter()
+            .find(|answer| answer.answer.part == part)
+            .map(|answer| answer.elapsed)
+    };
+    let partial = PhaseStats {
+        parse: None,
+        part1: part_time(Part::One),
+        part2: part_time(Part::Two),
+        memory: PhaseMemory::default(),
+    };
+    let (phases, error) = match result {
+        Ok(Ok(phases)) => (phases, None),
+        Ok(Err(error)) => (partial, Some(error.to_string())),
+        Err(payload) => (
+            partial,
+            Some(format!("panicked: {}", panic_message(payload))),
+        ),
+    };
+    DayRun {
+        year: day.year,
+        day: day.day,
+        answers,
+        error,
+        phases,
+        elapsed,
+    }
+}
+
+enum Event {
+    Answer(TimedAnswer),
+    Done(DayRun),
+}
+
+// Like `run_catching`, but giving up after `timeout`, for days that might never finish. The run
+// goes on its own thread, and if it times out we keep whatever answers it got to. There's no
+// stopping a thread, so a run that's timed out carries on in the background (and takes a CPU
+// away from the runs after it) until we exit.
+pub fn run_with_timeout(
+    day: &'static Day,
+    input_path: Option<&str>,
+    part: Option<Part>,
+    timeout: Duration,
+) -> DayRun {
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let path = input_path.map(String::from);
+    thread::spawn(move || {
+        let run = run_catching_reporting(day, path.as_deref(), part, &mut |answer| {
+            let _ = sender.send(Event::Answer(answer.clone()));
+        });
+        let _ = sender.send(Event::Done(run));
+    });
+
+    let mut answers = vec![];
+    let deadline = start + timeout;
+    loop {
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(Event::Answer(answer)) => answers.push(answer),
+            Ok(Event::Done(run)) => return run,
+            Err(_) => break,
+        }
+    }
+    // The parse time went with the run, but the parts that finished have theirs.
+    let part_time = |part| {
+        answers
+            .iter()
+            .find(|answer: &&TimedAnswer| answer.answer.part == part)
+            .map(|answer| answer.elapsed)
+    };
+    let phases = PhaseStats {
+        part1: part_time(Part::One),
+        part2: part_time(Part::Two),
+        ..PhaseStats::default()
+    };
+    DayRun {
+        year: day.year,
+        day: day.day,
+        answers,
+        error: Some(format!("timed out after {:.0?}", timeout)),
+        phases,
+        elapsed: start.elapsed(),
+    }
+}
+
+// Run each day on its own input across a pool of `threads` workers. Workers pick up the next day
+// that nobody has started yet, so one slow day doesn't hold up a whole batch, and a day that's
+// still going after `timeout` is given up on. The results come back in the same order as `days`.
+pub fn run_all(
+    days: &[&'static Day],
+    part: Option<Part>,
+    threads: usize,
+    timeout: Duration,
+) -> Vec<DayRun> {
+    let next_day = AtomicUsize::new(0);
+    let mut runs = thread::scope(|s| {
+        let workers = (0..threads.clamp(1, days.len().max(1)))
+            .map(|_| {
+                s.spawn(|| {
+                    let mut runs = vec![];
+                    while let Some(day) = days.get(next_day.fetch_add(1, Ordering::Relaxed)) {
+                        runs.push(run_with_timeout(day, None, part, timeout));
+                    }
+                    runs
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    runs.sort_by_key(|run| {
+        days.iter()
+            .position(|day| day.year == run.year && day.day == run.day)
+    });
+    runs
+}
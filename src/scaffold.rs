@@ -0,0 +1,296 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// What a new day starts out as. `YYYY` is replaced with the year and `NN` with the zero-padded
+// day number. It parses the input into lines and counts them, which is enough to run (and test)
+// straight away.
+const TEMPLATE: &str = r#"use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::solution::Solution;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(error::lines(input)
+            .map(|line| line.text.to_string())
+            .collect())
+    }
+
+    fn part1(&self, lines: &Vec<String>) -> Result<Answer, SolveError> {
+        Ok(lines.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/YYYY/dayNN.txt");
+
+    // Paste the example into the fixture and put its answer here.
+    #[test]
+    fn test_part1() {
+        let input = DayNN.parse(EXAMPLE).unwrap();
+        assert_eq!(DayNN.part1(&input), Ok(Answer::Int(0)));
+    }
+}
+"#;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    NotADay(u8),
+    AlreadyExists(PathBuf),
+    CantRegister(PathBuf),
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::NotADay(day) => write!(f, "There's no day {} in Advent of Code", day),
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::CantRegister(path) => {
+                write!(
+                    f,
+                    "Couldn't find where to add the day in {}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io { path, error } => {
+                write!(f, "Couldn't update {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+// Put `line` into the run of lines that `key_of` can read a key (a day, or a year) from,
+// keeping them in order. Returns None if there's no such run, or if the key's already in it.
+fn insert_in_order<K: Ord>(
+    contents: &str,
+    key: K,
+    line: &str,
+    key_of: impl Fn(&str) -> Option<K>,
+) -> Option<String> {
+    let lines = contents.lines().collect::<Vec<&str>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| key_of(line).map(|k| (index, k)))
+        .collect::<Vec<(usize, K)>>();
+    if keyed.iter().any(|(_, k)| *k == key) {
+        return None;
+    }
+    let index = match keyed.iter().find(|(_, k)| *k > key) {
+        Some((index, _)) => *index,
+        None => keyed.last()?.0 + 1,
+    };
+    let mut lines = lines;
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io_error = |error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    std::fs::write(path, contents).map_err(io_error)
+}
+
+// Add a new day to the crate under `root`: the module (from the template above), its `mod`
+// line (and the year's module, if it's the first day of a year), its registry entry, an empty
+// data file and an empty example fixture for its test. Everything is checked before anything is
+// written, so a failure leaves the tree alone. Returns the files that were created or changed.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::NotADay(day));
+    }
+    let year_name = format!("year{}", year);
+    let name = format!("day{:02}", day);
+    let year_dir = root.join("src").join(&year_name);
+    let module_path = year_dir.join(format!("{}.rs", name));
+    if module_path.exists() {
+        return Err(ScaffoldError::AlreadyExists(module_path));
+    }
+
+    let mut updates = vec![];
+    let day_line = format!("pub mod {};", name);
+    let year_mod_path = year_dir.join("mod.rs");
+    if year_mod_path.exists() {
+        let year_mod = insert_in_order(&read(&year_mod_path)?, day, &day_line, |line| {
+            line.strip_prefix("pub mod day")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        })
+        .ok_or_else(|| ScaffoldError::CantRegister(year_mod_path.clone()))?;
+        updates.push((year_mod_path, year_mod));
+    } else {
+        let lib_path = root.join("src").join("lib.rs");
+        let lib = insert_in_order(
+            &read(&lib_path)?,
+            year,
+            &format!("pub mod {};", year_name),
+            |line| {
+                line.strip_prefix("pub mod year")?
+                    .strip_suffix(';')?
+                    .parse()
+                    .ok()
+            },
+        )
+        .ok_or_else(|| ScaffoldError::CantRegister(lib_path.clone()))?;
+        updates.push((lib_path, lib));
+        updates.push((
+            year_mod_path,
+            format!("// The days of Advent of Code {}.\n{}\n", year, day_line),
+        ));
+    }
+
+    let registry_path = root.join("src").join("registry.rs");
+    let registry_line = format!(
+        "    Day::new({}, {}, &crate::{}::{}::Day{:02}),",
+        year, day, year_name, name, day
+    );
+    let registry = insert_in_order(
+        &read(&registry_path)?,
+        (year, day),
+        &registry_line,
+        |line| {
+            let (year, rest) = line.strip_prefix("    Day::new(")?.split_once(", ")?;
+            let (day, _) = rest.split_once(", ")?;
+            Some((year.parse().ok()?, day.parse().ok()?))
+        },
+    )
+    .ok_or_else(|| ScaffoldError::CantRegister(registry_path.clone()))?;
+    updates.push((registry_path, registry));
+
+    let mut changed = vec![];
+    let module = TEMPLATE
+        .replace("YYYY", &year.to_string())
+        .replace("NN", &format!("{:02}", day));
+    write(&module_path, &module)?;
+    changed.push(module_path);
+    for (path, contents) in updates {
+        write(&path, &contents)?;
+        changed.push(path);
+    }
+    // Don't clobber an input or example that's already been put in place.
+    let file_name = format!("{}.txt", name);
+    for path in [
+        root.join("data").join(year.to_string()).join(&file_name),
+        root.join("tests")
+            .join("fixtures")
+            .join(year.to_string())
+            .join(&file_name),
+    ] {
+        if !path.exists() {
+            write(&path, "")?;
+            changed.push(path);
+        }
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::scratch_dir;
+
+    fn scratch_root(name: &str) -> PathBuf {
+        let root = scratch_dir(&format!("scaffold-{}", name));
+        write(
+            &root.join("src").join("lib.rs"),
+            "pub mod answer;\npub mod error;\npub mod year2023;\n",
+        )
+        .unwrap();
+        write(
+            &root.join("src").join("year2023").join("mod.rs"),
+            "// The days of Advent of Code 2023.\npub mod day01;\npub mod day05;\n",
+        )
+        .unwrap();
+        write(
+            &root.join("src").join("registry.rs"),
+            "pub static DAYS: &[Day] = &[\n    Day::new(2023, 1, &crate::year2023::day01::Day01),\n    \
+             Day::new(2023, 5, &crate::year2023::day05::Day05),\n];\n",
+        )
+        .unwrap();
+        write(&root.join("src").join("year2023").join("day01.rs"), "").unwrap();
+        root
+    }
+
+    #[test]
+    fn test_new_day_registers_in_order() {
+        let root = scratch_root("order");
+        let changed = new_day(&root, 2023, 3).unwrap();
+        assert_eq!(changed.len(), 5);
+
+        assert_eq!(
+            read(&root.join("src").join("year2023").join("mod.rs")).unwrap(),
+            "// The days of Advent of Code 2023.\npub mod day01;\npub mod day03;\npub mod day05;\n"
+        );
+        assert!(read(&root.join("src").join("registry.rs"))
+            .unwrap()
+            .contains(
+            "Day01),\n    Day::new(2023, 3, &crate::year2023::day03::Day03),\n    Day::new(2023, 5"
+        ));
+        let module = read(&root.join("src").join("year2023").join("day03.rs")).unwrap();
+        assert!(module.contains("pub struct Day03;"));
+        assert!(module.contains("tests/fixtures/2023/day03.txt"));
+        assert_eq!(
+            read(&root.join("data").join("2023").join("day03.txt")).unwrap(),
+            ""
+        );
+
+        // The first day of a year brings the year's module with it, and goes after every day of
+        // the years before.
+        new_day(&root, 2024, 1).unwrap();
+        assert_eq!(
+            read(&root.join("src").join("lib.rs")).unwrap(),
+            "pub mod answer;\npub mod error;\npub mod year2023;\npub mod year2024;\n"
+        );
+        assert_eq!(
+            read(&root.join("src").join("year2024").join("mod.rs")).unwrap(),
+            "// The days of Advent of Code 2024.\npub mod day01;\n"
+        );
+        assert!(read(&root.join("src").join("registry.rs"))
+            .unwrap()
+            .ends_with("    Day::new(2024, 1, &crate::year2024::day01::Day01),\n];\n"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_leaves_existing_days_alone() {
+        let root = scratch_root("existing");
+        assert!(matches!(
+            new_day(&root, 2023, 1),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        // Declared but with no module: nothing should be written.
+        assert!(matches!(
+            new_day(&root, 2023, 5),
+            Err(ScaffoldError::CantRegister(_))
+        ));
+        assert!(!root.join("src").join("year2023").join("day05.rs").exists());
+        assert!(matches!(
+            new_day(&root, 2023, 26),
+            Err(ScaffoldError::NotADay(26))
+        ));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
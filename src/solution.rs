@@ -0,0 +1,83 @@
+use std::any::Any;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{ParseError, SolveError};
+
+// Every day implements `Solution`. Parsing is kept separate from the two parts so that the
+// runner can call each phase on its own.
+pub trait Solution {
+    type Input;
+
+    // The puzzle's title, and a line on how we go about solving it, for the report.
+    const TITLE: &'static str = "";
+    const NOTES: &'static str = "";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    // A part that can't get an answer out of the input says why, rather than passing off
+    // something made up as the answer.
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    // Not every day has a second part (or at least not one I've managed to solve).
+    fn part2(&self, _input: &Self::Input) -> Option<Result<Answer, SolveError>> {
+        None
+    }
+
+    // Make up a random input that the real input could have been, for seeing how a day scales
+    // and finding cases our own input doesn't cover. What `size` counts (lines, grid width, ...)
+    // is up to each day, as is what it means when it's not given.
+    fn generate(&self, _rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+}
+
+// Object-safe version of `Solution`, so that days with different `Input` types can live in the
+// same table. The parsed input is passed around as `Any` and downcast again by each part.
+pub trait Runner: Sync {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn run_part1(&self, input: &dyn Any) -> Result<Answer, SolveError>;
+    fn run_part2(&self, input: &dyn Any) -> Option<Result<Answer, SolveError>>;
+    fn generate_input(&self, rng: &mut Rng, size: Option<usize>) -> Option<String>;
+    fn title(&self) -> &'static str;
+    fn notes(&self) -> &'static str;
+}
+
+impl<S> Runner for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn run_part1(&self, input: &dyn Any) -> Result<Answer, SolveError> {
+        self.part1(
+            input
+                .downcast_ref()
+                .expect("Input parsed by a different day"),
+        )
+    }
+
+    fn run_part2(&self, input: &dyn Any) -> Option<Result<Answer, SolveError>> {
+        self.part2(
+            input
+                .downcast_ref()
+                .expect("Input parsed by a different day"),
+        )
+    }
+
+    fn generate_input(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        self.generate(rng, size)
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn notes(&self) -> &'static str {
+        S::NOTES
+    }
+}
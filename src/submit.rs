@@ -0,0 +1,360 @@
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Part;
+use crate::client::{Client, ClientError};
+
+// What the site made of an answer we sent it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // We've submitted too recently, and have to wait this long (if the page said) to try again.
+    Wait(Option<Duration>),
+    // The part's already been solved, or part 1 hasn't been yet.
+    WrongLevel,
+    Unrecognised,
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::TooHigh => write!(f, "too high"),
+            Response::TooLow => write!(f, "too low"),
+            Response::Wrong => write!(f, "wrong"),
+            Response::Wait(Some(wait)) => {
+                write!(f, "submitted too recently, wait {}s", wait.as_secs())
+            }
+            Response::Wait(None) => write!(f, "submitted too recently"),
+            Response::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+            Response::Unrecognised => write!(f, "didn't understand the response"),
+        }
+    }
+}
+
+// The site replies with a whole HTML page, but all we care about is which of a handful of
+// sentences it contains.
+pub fn parse_response(page: &str) -> Response {
+    if page.contains("not the right answer") {
+        if page.contains("your answer is too high") {
+            Response::TooHigh
+        } else if page.contains("your answer is too low") {
+            Response::TooLow
+        } else {
+            Response::Wrong
+        }
+    } else if page.contains("the right answer") {
+        Response::Correct
+    } else if page.contains("You gave an answer too recently") {
+        Response::Wait(parse_wait(page))
+    } else if page.contains("solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unrecognised
+    }
+}
+
+// Pick the wait out of "You have 1m 23s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for token in wait.split_whitespace() {
+        seconds += match token.split_at(token.len().checked_sub(1)?) {
+            (n, "m") => n.parse::<u64>().ok()? * 60,
+            (n, "s") => n.parse::<u64>().ok()?,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+// Only answers that count against us go in the history; waits and level mix-ups don't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+// Everything we've submitted, so that we never send the same wrong answer twice, plus when the
+// site last told us we can try again (in seconds since the epoch).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<u64>,
+}
+
+impl History {
+    // A missing history file just means we haven't submitted anything yet.
+    pub fn load(path: &str) -> Result<History, SubmitError> {
+        let history_error = |error: &dyn fmt::Display| SubmitError::History {
+            path: path.to_string(),
+            error: error.to_string(),
+        };
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|e| history_error(&e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(history_error(&e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), SubmitError> {
+        let history_error = |error: &dyn fmt::Display| SubmitError::History {
+            path: path.to_string(),
+            error: error.to_string(),
+        };
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| history_error(&e))?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| history_error(&e))?;
+        std::fs::write(path, json + "\n").map_err(|e| history_error(&e))
+    }
+
+    // Why we shouldn't bother sending `answer`, if there's a reason. As well as exact repeats,
+    // a number that's at least something we've been told is too high must be too high too (and
+    // likewise for too low).
+    pub fn reason_not_to_submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Option<String> {
+        if let Some(not_before) = self.not_before.filter(|not_before| *not_before > now) {
+            return Some(format!(
+                "the site asked us to wait another {}s",
+                not_before - now
+            ));
+        }
+        let number = answer.parse::<i128>().ok();
+        for submission in self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+        {
+            if submission.verdict == Verdict::Correct {
+                return Some(format!("already solved with {}", submission.answer));
+            }
+            if submission.answer == answer {
+                return Some(format!(
+                    "already tried it, and it was {}",
+                    submission.verdict
+                ));
+            }
+            let previous = submission.answer.parse::<i128>().ok();
+            match (number, previous, submission.verdict) {
+                (Some(n), Some(p), Verdict::TooHigh) if n >= p => {
+                    return Some(format!("{} was already too high", p));
+                }
+                (Some(n), Some(p), Verdict::TooLow) if n <= p => {
+                    return Some(format!("{} was already too low", p));
+                }
+                _ => (),
+            }
+        }
+        None
+    }
+
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        response: &Response,
+        now: u64,
+    ) {
+        let verdict = match response {
+            Response::Correct => Verdict::Correct,
+            Response::TooHigh => Verdict::TooHigh,
+            Response::TooLow => Verdict::TooLow,
+            Response::Wrong => Verdict::Wrong,
+            Response::Wait(wait) => {
+                // If we don't know how long to wait, a minute is what the site usually asks for.
+                let wait = wait.unwrap_or(Duration::from_secs(60));
+                self.not_before = Some(now + wait.as_secs());
+                return;
+            }
+            Response::WrongLevel | Response::Unrecognised => return,
+        };
+        self.submissions.push(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Client(ClientError),
+    History { path: String, error: String },
+    Refused(String),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Client(error) => write!(f, "{}", error),
+            SubmitError::History { path, error } => {
+                write!(f, "Couldn't use history file {}: {}", path, error)
+            }
+            SubmitError::Refused(reason) => write!(f, "Not submitting: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(error: ClientError) -> SubmitError {
+        SubmitError::Client(error)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+// Submit an answer unless the history says it's pointless, and remember what the site said.
+pub fn submit(
+    client: &Client,
+    history_path: &str,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Response, SubmitError> {
+    let mut history = History::load(history_path)?;
+    if let Some(reason) = history.reason_not_to_submit(year, day, part, answer, now()) {
+        return Err(SubmitError::Refused(reason));
+    }
+    let response = parse_response(&client.post_answer(year, day, part, answer)?);
+    history.record(year, day, part, answer, &response, now());
+    history.save(history_path)?;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::serve;
+    use crate::testing::scratch_dir;
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("<p>That's the right answer! You are one gold star closer.</p>"),
+            Response::Correct
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.</p>"),
+            Response::TooHigh
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too low.</p>"),
+            Response::TooLow
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer. If you're stuck...</p>"),
+            Response::Wrong
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently. You have 1m 23s left to wait.</p>"),
+            Response::Wait(Some(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            parse_response("<p>You don't seem to be solving the right level.</p>"),
+            Response::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_reason_not_to_submit() {
+        let refused = |history: &History, part: Part, answer: &str, now: u64| {
+            history
+                .reason_not_to_submit(2023, 1, part, answer, now)
+                .is_some()
+        };
+        let mut history = History::default();
+        history.record(2023, 1, Part::One, "100", &Response::TooHigh, 0);
+        history.record(2023, 1, Part::One, "10", &Response::TooLow, 0);
+        history.record(2023, 1, Part::One, "abc", &Response::Wrong, 0);
+
+        assert!(refused(&history, Part::One, "100", 0));
+        assert!(refused(&history, Part::One, "150", 0));
+        assert!(refused(&history, Part::One, "5", 0));
+        assert!(refused(&history, Part::One, "abc", 0));
+        assert!(!refused(&history, Part::One, "50", 0));
+        assert!(!refused(&history, Part::Two, "100", 0));
+        assert!(history
+            .reason_not_to_submit(2022, 1, Part::One, "100", 0)
+            .is_none());
+
+        history.record(2023, 1, Part::One, "50", &Response::Wait(None), 1000);
+        assert!(refused(&history, Part::Two, "50", 1059));
+        assert!(!refused(&history, Part::Two, "50", 1060));
+
+        history.record(2023, 1, Part::One, "50", &Response::Correct, 2000);
+        assert!(refused(&history, Part::One, "51", 2000));
+    }
+
+    #[test]
+    fn test_submit_never_resends_a_wrong_answer() {
+        let (base_url, requests) = serve(vec![(
+            200,
+            "<p>That's not the right answer; your answer is too low.</p>",
+        )]);
+        let client = Client::new(&base_url, "abc123");
+        let dir = scratch_dir("submit-history");
+        let history_path = dir.join("submissions.json").to_string_lossy().into_owned();
+
+        assert_eq!(
+            submit(&client, &history_path, 2023, 9, Part::One, "12").unwrap(),
+            Response::TooLow
+        );
+        assert_eq!(requests.recv().unwrap().body, "level=1&answer=12");
+
+        // The server only answers once, so this would fail if it got as far as posting.
+        assert!(matches!(
+            submit(&client, &history_path, 2023, 9, Part::One, "11"),
+            Err(SubmitError::Refused(_))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
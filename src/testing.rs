@@ -0,0 +1,44 @@
+// Bits and pieces the tests of more than one module need.
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::answer::{Part, PuzzleAnswer, TimedAnswer};
+use crate::runner::{DayRun, PhaseStats};
+
+// A fresh, empty directory to write files into. Each test should use its own name, since tests
+// run at the same time; the process ID keeps separate runs apart.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc2023-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// A 2023 run of `day` with these answers, each 5ms in, for checking how runs get shown. Part 2
+// is slow, at 1.5s.
+pub fn day_run(day: u8, answers: &[(Part, &str)], error: Option<&str>) -> DayRun {
+    let answers = answers
+        .iter()
+        .map(|(part, answer)| TimedAnswer {
+            answer: PuzzleAnswer {
+                year: 2023,
+                day,
+                part: *part,
+                answer: (*answer).into(),
+            },
+            elapsed: Duration::from_millis(5),
+        })
+        .collect();
+    DayRun {
+        year: 2023,
+        day,
+        answers,
+        error: error.map(String::from),
+        phases: PhaseStats {
+            part1: Some(Duration::from_millis(5)),
+            part2: Some(Duration::from_millis(1500)),
+            ..PhaseStats::default()
+        },
+        elapsed: Duration::from_secs(2),
+    }
+}
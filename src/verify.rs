@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::answer::Part;
+use crate::error::RunError;
+use crate::registry::{self, Day};
+use crate::runner;
+
+// The answers file has the same shape as `--format json` output (minus the timings), so a run
+// we're happy with can be saved straight into it.
+#[derive(Deserialize)]
+struct AnswersFile {
+    answers: Vec<ExpectedAnswer>,
+}
+
+#[derive(Deserialize)]
+struct ExpectedAnswer {
+    // Answers files from before we did more than one year don't say which year they're for.
+    #[serde(default = "default_year")]
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: serde_json::Value,
+}
+
+fn default_year() -> u16 {
+    registry::DEFAULT_YEAR
+}
+
+// Known-good answers keyed by year, day and part. Answers are compared as the strings we'd print, so
+// it doesn't matter whether the file stores a number or a string.
+pub struct ExpectedAnswers(HashMap<(u16, u8, Part), String>);
+
+impl ExpectedAnswers {
+    pub fn load(path: &str) -> Result<ExpectedAnswers, RunError> {
+        let contents = std::fs::read_to_string(path).map_err(|error| RunError::Input {
+            path: path.to_string(),
+            error,
+        })?;
+        let file: AnswersFile =
+            serde_json::from_str(&contents).map_err(|error| RunError::Malformed {
+                path: path.to_string(),
+                error: error.to_string(),
+            })?;
+        Ok(ExpectedAnswers(
+            file.answers
+                .into_iter()
+                .map(|expected| {
+                    let answer = match expected.answer {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    ((expected.year, expected.day, expected.part), answer)
+                })
+                .collect(),
+        ))
+    }
+
+    fn get(&self, day: &Day, part: Part) -> Option<&String> {
+        self.0.get(&(day.year, day.day, part))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail(reason) => write!(f, "FAIL ({})", reason),
+            Outcome::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+// Run a day and check each part against what we expect. Days (or parts) with nothing to check
+// against aren't run at all, and a part that errors or panics before giving an answer fails.
+pub fn verify_day(day: &Day, expected: &ExpectedAnswers) -> Vec<(Part, Outcome)> {
+    let expected_part1 = expected.get(day, Part::One);
+    let expected_part2 = expected.get(day, Part::Two);
+    let part = match (expected_part1, expected_part2) {
+        (None, None) => {
+            return vec![(Part::One, Outcome::Missing), (Part::Two, Outcome::Missing)];
+        }
+        (Some(_), None) => Some(Part::One),
+        (None, Some(_)) => Some(Part::Two),
+        (Some(_), Some(_)) => None,
+    };
+
+    let run = runner::run_catching(day, None, part);
+    let answers: HashMap<Part, String> = run
+        .answers
+        .into_iter()
+        .map(|timed| (timed.answer.part, timed.answer.answer.to_string()))
+        .collect();
+
+    [(Part::One, expected_part1), (Part::Two, expected_part2)]
+        .into_iter()
+        .map(|(part, expected)| {
+            let outcome = match (expected, answers.get(&part), &run.error) {
+                (None, _, _) => Outcome::Missing,
+                (Some(expected), Some(actual), _) if actual == expected => Outcome::Pass,
+                (Some(expected), Some(actual), _) => {
+                    Outcome::Fail(format!("expected {}, got {}", expected, actual))
+                }
+                (Some(_), None, Some(error)) => Outcome::Fail(error.clone()),
+                (Some(expected), None, None) => {
+                    Outcome::Fail(format!("expected {}, got no answer", expected))
+                }
+            };
+            (part, outcome)
+        })
+        .collect()
+}
+
+// Verify every given day, printing a line per part and a summary. Returns whether everything we
+// had an answer for passed.
+pub fn verify_days(days: &[&Day], expected: &ExpectedAnswers) -> bool {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        for (part, outcome) in verify_day(day, expected) {
+            match outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Fail(_) => failed += 1,
+                Outcome::Missing => missing += 1,
+            }
+            println!("Day {} part {}: {}", day.day, part, outcome);
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::scratch_dir;
+
+    #[test]
+    fn test_load_errors() {
+        let dir = scratch_dir("verify-answers");
+        let path = dir.join("answers.json");
+        let path = path.to_str().unwrap();
+        assert!(matches!(
+            ExpectedAnswers::load(path),
+            Err(RunError::Input { .. })
+        ));
+        std::fs::write(path, "{\"answers\": [{\"day\": 1}]}").unwrap();
+        assert!(matches!(
+            ExpectedAnswers::load(path),
+            Err(RunError::Malformed { .. })
+        ));
+        std::fs::write(
+            path,
+            "{\"answers\": [{\"day\": 1, \"part\": 1, \"answer\": 142}]}",
+        )
+        .unwrap();
+        let expected = ExpectedAnswers::load(path).unwrap();
+        let day = registry::find(2023, 1).unwrap();
+        assert_eq!(expected.get(day, Part::One), Some(&"142".to_string()));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
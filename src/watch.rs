@@ -0,0 +1,290 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::answer::Part;
+use crate::registry::Day;
+
+// The inputs we rerun a day on: its puzzle input plus any examples for it (dayNN.txt and
+// dayNN_*.txt) in the year's fixtures directory.
+pub fn watched_inputs(day: &Day, fixtures_dir: &Path) -> Vec<PathBuf> {
+    let name = format!("day{:02}", day.day);
+    let mut inputs = vec![PathBuf::from(day.input_path())];
+    let mut fixtures = std::fs::read_dir(fixtures_dir.join(day.year.to_string()))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem == name || stem.starts_with(&format!("{}_", name)))
+        })
+        .collect::<Vec<PathBuf>>();
+    fixtures.sort();
+    inputs.extend(fixtures);
+    inputs
+}
+
+// Enough to notice that a file's been written to (or has appeared or gone away).
+type Fingerprint = BTreeMap<PathBuf, Option<(SystemTime, u64)>>;
+
+fn fingerprint(paths: &[PathBuf]) -> Fingerprint {
+    paths
+        .iter()
+        .map(|path| {
+            let stamp = std::fs::metadata(path)
+                .ok()
+                .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+            (path.clone(), stamp)
+        })
+        .collect()
+}
+
+// What one run of a day on one input came to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputRun {
+    pub answers: BTreeMap<Part, String>,
+    pub error: Option<String>,
+}
+
+// Pick the answers out of the runner's text output ("Day 17 part 1: 102").
+fn parse_answers(output: &str) -> BTreeMap<Part, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (label, answer) = line.strip_prefix("Day ")?.split_once(": ")?;
+            let (_, part) = label.split_once(" part ")?;
+            Some((part.parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+// Read everything from one of a child's pipes on a thread of its own. If nobody reads them, a
+// child that prints more than fits in the pipe blocks until we kill it.
+fn drain(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = vec![];
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+// Run the day on an input in a child copy of this binary, so that a day that never finishes (or
+// a rebuilt binary) doesn't take the watcher down with it. Anything printed before a timeout
+// still counts.
+fn run_input(exe: &Path, day: &Day, input: &Path, timeout: Duration) -> InputRun {
+    let child = Command::new(exe)
+        .arg(day.day.to_string())
+        .arg("--year")
+        .arg(day.year.to_string())
+        .arg("--input")
+        .arg(input)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            return InputRun {
+                answers: BTreeMap::new(),
+                error: Some(format!("couldn't run {}: {}", exe.display(), e)),
+            }
+        }
+    };
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+    let start = Instant::now();
+    let mut timed_out = false;
+    while child.try_wait().ok().flatten().is_none() {
+        if start.elapsed() > timeout {
+            let _ = child.kill();
+            timed_out = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    let status = match child.wait() {
+        Ok(status) => status,
+        Err(e) => {
+            return InputRun {
+                answers: BTreeMap::new(),
+                error: Some(e.to_string()),
+            }
+        }
+    };
+    let output = |pipe: Option<thread::JoinHandle<Vec<u8>>>| {
+        let bytes = pipe.and_then(|pipe| pipe.join().ok()).unwrap_or_default();
+        String::from_utf8_lossy(&bytes).into_owned()
+    };
+    let (stdout, stderr) = (output(stdout), output(stderr));
+    let error = if timed_out {
+        Some(format!("timed out after {:.0?}", timeout))
+    } else if !status.success() {
+        // Skip the "run with RUST_BACKTRACE" hint after a panic.
+        let message = stderr
+            .lines()
+            .filter(|line| !line.starts_with("note: "))
+            .collect::<Vec<&str>>()
+            .join(" ");
+        Some(match message.is_empty() {
+            true => format!("exited with {}", status),
+            false => message,
+        })
+    } else {
+        None
+    };
+    InputRun {
+        answers: parse_answers(&stdout),
+        error,
+    }
+}
+
+// Describe a run next to the one before it on the same input, a line per part.
+pub fn describe(previous: Option<&InputRun>, current: &InputRun) -> Vec<String> {
+    let mut lines = vec![];
+    for part in [Part::One, Part::Two] {
+        let before = previous.and_then(|run| run.answers.get(&part));
+        let change = match (before, current.answers.get(&part)) {
+            (None, None) => continue,
+            (Some(before), None) => format!("no answer (was {})", before),
+            (None, Some(after)) if previous.is_none() => after.clone(),
+            (None, Some(after)) => format!("{} (new)", after),
+            (Some(before), Some(after)) if before == after => format!("{} (unchanged)", after),
+            (Some(before), Some(after)) => format!("{} (was {})", after, before),
+        };
+        lines.push(format!("  part {}: {}", part, change));
+    }
+    if let Some(error) = &current.error {
+        lines.push(format!("  error: {}", error));
+    }
+    lines
+}
+
+// Run the day on each of its inputs whenever one of them (or the binary itself, so rebuilding in
+// another terminal picks up source changes) changes, printing how the answers moved. Never
+// returns.
+pub fn watch(exe: &Path, day: &Day, fixtures_dir: &Path, interval: Duration, timeout: Duration) {
+    let mut previous: BTreeMap<PathBuf, InputRun> = BTreeMap::new();
+    let mut last_seen: Option<Fingerprint> = None;
+    loop {
+        let mut watched = watched_inputs(day, fixtures_dir);
+        watched.push(exe.to_path_buf());
+        let seen = fingerprint(&watched);
+        if last_seen.as_ref() != Some(&seen) {
+            if let Some(last_seen) = &last_seen {
+                let changed = seen
+                    .keys()
+                    .chain(last_seen.keys())
+                    .collect::<BTreeSet<&PathBuf>>()
+                    .into_iter()
+                    .filter(|path| seen.get(*path) != last_seen.get(*path))
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<String>>();
+                println!("--- {} changed ---", changed.join(", "));
+            }
+            for input in watched.iter().filter(|path| path.as_path() != exe) {
+                let run = run_input(exe, day, input, timeout);
+                println!("{}", input.display());
+                for line in describe(previous.get(input), &run) {
+                    println!("{}", line);
+                }
+                previous.insert(input.clone(), run);
+            }
+            last_seen = Some(seen);
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::testing::scratch_dir;
+
+    fn input_run(part1: Option<&str>, part2: Option<&str>, error: Option<&str>) -> InputRun {
+        InputRun {
+            answers: [(Part::One, part1), (Part::Two, part2)]
+                .into_iter()
+                .filter_map(|(part, answer)| Some((part, answer?.to_string())))
+                .collect(),
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_watched_inputs() {
+        let inputs = watched_inputs(
+            registry::find(2023, 8).unwrap(),
+            Path::new("tests/fixtures"),
+        );
+        assert_eq!(
+            inputs,
+            vec![
+                PathBuf::from("data/2023/day08.txt"),
+                PathBuf::from("tests/fixtures/2023/day08.txt"),
+                PathBuf::from("tests/fixtures/2023/day08_part2.txt"),
+            ]
+        );
+    }
+
+    // A child that prints more than a pipe holds has to be read from as it goes, or it never
+    // finishes.
+    #[cfg(unix)]
+    #[test]
+    fn test_run_input_chatty_child() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = scratch_dir("watch-chatty");
+        let exe = dir.join("chatty.sh");
+        std::fs::write(
+            &exe,
+            "#!/bin/sh\nhead -c 1000000 /dev/zero >&2\necho 'Day 1 part 1: 142'\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let day = registry::find(2023, 1).unwrap();
+        let run = run_input(&exe, day, Path::new("unused.txt"), Duration::from_secs(10));
+        assert_eq!(run, input_run(Some("142"), None, None));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("Day 17 part 1: 102\nDay 17 part 2: 94\n");
+        assert_eq!(answers, input_run(Some("102"), Some("94"), None).answers);
+    }
+
+    #[test]
+    fn test_describe() {
+        let first = input_run(Some("102"), Some("94"), None);
+        assert_eq!(
+            describe(None, &first),
+            vec!["  part 1: 102", "  part 2: 94"]
+        );
+
+        let second = input_run(Some("102"), Some("90"), None);
+        assert_eq!(
+            describe(Some(&first), &second),
+            vec!["  part 1: 102 (unchanged)", "  part 2: 90 (was 94)"]
+        );
+
+        let third = input_run(Some("102"), None, Some("timed out after 10s"));
+        assert_eq!(
+            describe(Some(&second), &third),
+            vec![
+                "  part 1: 102 (unchanged)",
+                "  part 2: no answer (was 90)",
+                "  error: timed out after 10s"
+            ]
+        );
+        assert_eq!(
+            describe(Some(&third), &second),
+            vec!["  part 1: 102 (unchanged)", "  part 2: 90 (new)"]
+        );
+    }
+}
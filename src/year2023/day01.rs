@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+// The digits as part 2 spells them out.
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    const TITLE: &'static str = "Trebuchet?!";
+    const NOTES: &'static str =
+        "Find the first and last digit on each line, counting spelled-out digits too in part 2.";
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        error::lines(input)
+            .map(|line| {
+                let has_digit = line.text.chars().any(|c| ('1'..='9').contains(&c))
+                    || WORDS.iter().any(|word| line.text.contains(word));
+                if has_digit {
+                    Ok(line.text.to_string())
+                } else {
+                    Err(line.error(line.text, "expected a line with a digit in it"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(&self, calibration_lines: &Vec<String>) -> Result<Answer, SolveError> {
+        let part_1_valid_digits: HashMap<&str, u32> = HashMap::from([
+            ("1", 1),
+            ("2", 2),
+            ("3", 3),
+            ("4", 4),
+            ("5", 5),
+            ("6", 6),
+            ("7", 7),
+            ("8", 8),
+            ("9", 9),
+        ]);
+        Ok(solve(calibration_lines, &part_1_valid_digits).into())
+    }
+
+    fn part2(&self, calibration_lines: &Vec<String>) -> Option<Result<Answer, SolveError>> {
+        let part_2_valid_digits: HashMap<&str, u32> = HashMap::from([
+            ("1", 1),
+            ("2", 2),
+            ("3", 3),
+            ("4", 4),
+            ("5", 5),
+            ("6", 6),
+            ("7", 7),
+            ("8", 8),
+            ("9", 9),
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("four", 4),
+            ("five", 5),
+            ("six", 6),
+            ("seven", 7),
+            ("eight", 8),
+            ("nine", 9),
+        ]);
+        Some(Ok(solve(calibration_lines, &part_2_valid_digits).into()))
+    }
+
+    // Size is the number of lines. Every line gets at least one digit, so that part 1 counts every
+    // line too, as it does in the real input.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let lines = (0..size.unwrap_or(1000)).map(|_| {
+            let tokens = rng.usize(1..=8);
+            let digit_at = rng.usize(..tokens);
+            let mut line = String::new();
+            for token in 0..tokens {
+                match rng.u8(..3) {
+                    _ if token == digit_at => line.push(char::from(b'0' + rng.u8(1..=9))),
+                    0 => line.push(char::from(b'0' + rng.u8(1..=9))),
+                    1 => line += WORDS[rng.usize(..WORDS.len())],
+                    _ => line.extend((0..rng.usize(1..4)).map(|_| rng.lowercase())),
+                }
+            }
+            line
+        });
+        Some(generate::join_lines(lines))
+    }
+}
+
+fn solve(calibration_lines: &[String], valid_digits: &HashMap<&str, u32>) -> u32 {
+    let mut sum = 0;
+    for line in calibration_lines {
+        let mut numbers = Vec::new();
+        // Walk over the line sniffing out valid digits, and adding them to the numbers vec
+        for mut index in 0..line.len() {
+            for digit in valid_digits.keys() {
+                if line.len() >= index + digit.len() && &&line[index..index + digit.len()] == digit
+                {
+                    numbers.push(valid_digits.get(digit).unwrap());
+                    index += digit.len();
+                }
+            }
+        }
+        // Lines with only spelled-out digits (there are some in part 2's example) don't count for
+        // part 1.
+        if let (Some(first), Some(last)) = (numbers.first(), numbers.last()) {
+            sum += 10 * *first + *last;
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day01.txt");
+    const PART2_EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day01_part2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day01.parse(EXAMPLE).unwrap();
+        assert_eq!(Day01.part1(&input), Ok(Answer::Int(142)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day01.parse(PART2_EXAMPLE).unwrap();
+        assert_eq!(Day01.part2(&input), Some(Ok(Answer::Int(281))));
+    }
+
+    #[test]
+    fn test_parse_line_without_digits() {
+        assert!(Day01.parse("1abc2\nabc\n").is_err());
+        assert!(Day01.parse("abcone\n").is_ok());
+    }
+
+    // Lines of letters and digits, with at least one digit.
+    fn input() -> impl Strategy<Value = String> {
+        let alphabet = "abcdefghijklmnopqrstuvwxyz123456789";
+        prop::collection::vec(
+            (
+                grammar::chars(alphabet, 0..15),
+                grammar::chars("123456789", 1),
+                grammar::chars(alphabet, 0..15),
+            ),
+            1..10,
+        )
+        .prop_map(|lines| {
+            let lines = lines
+                .into_iter()
+                .map(|(before, digit, after)| before + &digit + &after)
+                .collect::<Vec<String>>();
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day01, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day01.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: usize,
+    pub r_min: usize,
+    pub g_min: usize,
+    pub b_min: usize,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    const TITLE: &'static str = "Cube Conundrum";
+    const NOTES: &'static str =
+        "The most cubes of each colour seen in a game are the fewest the bag can hold.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>, ParseError> {
+        let mut games: Vec<Game> = vec![];
+        for line in error::lines(input) {
+            // Track the maximum number of each colour we see - these are the minimum possible
+            // number of that colour in the bag.
+            let mut mins: HashMap<&str, usize> =
+                HashMap::from([("red", 0), ("green", 0), ("blue", 0)]);
+
+            let (_, draws) = line.split_once(line.text, ":")?;
+            for draw in draws.split(';') {
+                for colour in draw.split(',') {
+                    let (count, name) = line.split_once(colour.trim(), " ")?;
+                    let count = line.parse::<usize>(count, "a number of cubes")?;
+                    let min = mins
+                        .get_mut(name)
+                        .ok_or_else(|| line.error(name, "expected red, green or blue"))?;
+                    *min = (*min).max(count);
+                }
+            }
+            games.push(Game {
+                id: line.number,
+                r_min: mins["red"],
+                g_min: mins["green"],
+                b_min: mins["blue"],
+            });
+        }
+        Ok(games)
+    }
+
+    fn part1(&self, games: &Vec<Game>) -> Result<Answer, SolveError> {
+        Ok(games
+            .iter()
+            .filter_map(|g| {
+                if g.r_min <= 12 && g.g_min <= 13 && g.b_min <= 14 {
+                    Some(g.id)
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self, games: &Vec<Game>) -> Option<Result<Answer, SolveError>> {
+        Some(Ok(games
+            .iter()
+            .map(|g| g.r_min * g.g_min * g.b_min)
+            .sum::<usize>()
+            .into()))
+    }
+
+    // Size is the number of games.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let games = (1..=size.unwrap_or(100)).map(|id| {
+            let draws = (0..rng.usize(1..=6))
+                .map(|_| {
+                    let mut colours = vec!["red", "green", "blue"];
+                    rng.shuffle(&mut colours);
+                    colours.truncate(rng.usize(1..=3));
+                    colours
+                        .iter()
+                        .map(|colour| format!("{} {}", rng.usize(1..=20), colour))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .collect::<Vec<String>>();
+            format!("Game {}: {}", id, draws.join("; "))
+        });
+        Some(generate::join_lines(games))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day02.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day02.parse(EXAMPLE).unwrap();
+        assert_eq!(Day02.part1(&input), Ok(Answer::Int(8)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day02.parse(EXAMPLE).unwrap();
+        assert_eq!(Day02.part2(&input), Some(Ok(Answer::Int(2286))));
+    }
+
+    // Lines like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+    fn input() -> impl Strategy<Value = String> {
+        let colour = prop::sample::select(vec!["red", "green", "blue"]);
+        let draw = prop::collection::vec((1..20usize, colour), 1..4).prop_map(|cubes| {
+            cubes
+                .iter()
+                .map(|(count, colour)| format!("{} {}", count, colour))
+                .collect::<Vec<String>>()
+                .join(", ")
+        });
+        prop::collection::vec(prop::collection::vec(draw, 1..5), 1..10).prop_map(|games| {
+            let lines = games
+                .iter()
+                .enumerate()
+                .map(|(i, draws)| format!("Game {}: {}", i + 1, draws.join("; ")))
+                .collect::<Vec<String>>();
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day02, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day02.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, Line, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy)]
+pub enum SchematicPoint {
+    Empty,
+    Part(char),
+    PartNumber(u32),
+}
+
+// We've reached the end of a run of digits, and we want to insert their resolved value into the
+// grid at the locations of each of the digits.
+//
+// Clear `unresolved_digits` when we're finished.
+//
+// Parameters:
+//  - `unresolved_digits` is the collection of digits we've recorded
+//  - `row` is the row we've found them on
+//  - `col` is the column we found the last digit on
+//  - `schematic_grid` is the grid we want to insert into
+//  - `line` is the line of input we're on, for complaining about numbers that are too big
+fn resolve_digits(
+    unresolved_digits: &mut Vec<char>,
+    row: usize,
+    col: usize,
+    schematic_grid: &mut HashMap<(usize, usize), SchematicPoint>,
+    line: &Line,
+) -> Result<(), ParseError> {
+    // Work out what the number is
+    let resolved_number = unresolved_digits
+        .iter()
+        .collect::<String>()
+        .parse::<u32>()
+        .map_err(|_| line.error_at(col + 1 - unresolved_digits.len(), "part number is too big"))?;
+    // Insert it into the grid
+    for i in 0..unresolved_digits.len() {
+        schematic_grid.insert((row, col - i), SchematicPoint::PartNumber(resolved_number));
+    }
+    unresolved_digits.clear();
+    Ok(())
+}
+
+pub struct Schematic {
+    pub grid: HashMap<(usize, usize), SchematicPoint>,
+    pub height: usize,
+    pub width: usize,
+}
+
+// Find the distinct part numbers adjacent to the point at `row`, `col`.
+fn adjacent_part_numbers(schematic: &Schematic, row: usize, col: usize) -> HashSet<u32> {
+    let mut adjacent_part_numbers: HashSet<u32> = HashSet::new();
+    for x in 0..3 {
+        for y in 0..3 {
+            if row + y > 0 && col + x > 0 {
+                if let Some(SchematicPoint::PartNumber(n)) =
+                    schematic.grid.get(&(row + y - 1, col + x - 1))
+                {
+                    adjacent_part_numbers.insert(*n);
+                }
+            }
+        }
+    }
+    adjacent_part_numbers
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    const TITLE: &'static str = "Gear Ratios";
+    const NOTES: &'static str =
+        "Resolve each run of digits into its number, then look around every symbol for neighbours.";
+
+    fn parse(&self, input: &str) -> Result<Schematic, ParseError> {
+        let schematic_lines = error::grid(input)?;
+        let height = schematic_lines.len();
+        let width = schematic_lines[0].text.chars().count();
+        let mut schematic_grid: HashMap<(usize, usize), SchematicPoint> = HashMap::new();
+        for (row, line) in schematic_lines.iter().enumerate() {
+            let mut unresolved_digits: Vec<char> = vec![];
+            for (col, c) in line.text.chars().enumerate() {
+                match c {
+                    '.' => {
+                        if !unresolved_digits.is_empty() {
+                            resolve_digits(
+                                &mut unresolved_digits,
+                                row,
+                                col - 1,
+                                &mut schematic_grid,
+                                line,
+                            )?;
+                        }
+                        schematic_grid.insert((row, col), SchematicPoint::Empty);
+                    }
+                    _ => {
+                        if c.is_ascii_digit() {
+                            unresolved_digits.push(c);
+                        } else {
+                            if !unresolved_digits.is_empty() {
+                                resolve_digits(
+                                    &mut unresolved_digits,
+                                    row,
+                                    col - 1,
+                                    &mut schematic_grid,
+                                    line,
+                                )?;
+                            }
+                            schematic_grid.insert((row, col), SchematicPoint::Part(c));
+                        }
+                    }
+                };
+            }
+            if !unresolved_digits.is_empty() {
+                resolve_digits(
+                    &mut unresolved_digits,
+                    row,
+                    width - 1,
+                    &mut schematic_grid,
+                    line,
+                )?;
+            }
+        }
+        Ok(Schematic {
+            grid: schematic_grid,
+            height,
+            width,
+        })
+    }
+
+    fn part1(&self, schematic: &Schematic) -> Result<Answer, SolveError> {
+        let mut part_numbers: Vec<u32> = vec![];
+        for row in 0..schematic.height {
+            for col in 0..schematic.width {
+                if let Some(SchematicPoint::Part(_)) = schematic.grid.get(&(row, col)) {
+                    part_numbers.extend(adjacent_part_numbers(schematic, row, col));
+                }
+            }
+        }
+        Ok(part_numbers.iter().sum::<u32>().into())
+    }
+
+    fn part2(&self, schematic: &Schematic) -> Option<Result<Answer, SolveError>> {
+        let mut sum = 0;
+        for row in 0..schematic.height {
+            for col in 0..schematic.width {
+                if let Some(SchematicPoint::Part('*')) = schematic.grid.get(&(row, col)) {
+                    let adjacent_part_numbers = adjacent_part_numbers(schematic, row, col);
+                    if adjacent_part_numbers.len() == 2 {
+                        sum += adjacent_part_numbers.iter().product::<u32>();
+                    }
+                }
+            }
+        }
+        Some(Ok(sum.into()))
+    }
+
+    // Size is the width (and height) of the schematic.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+        let size = size.unwrap_or(140);
+        let rows = (0..size).map(|_| {
+            let mut row = String::new();
+            while row.len() < size {
+                match rng.u8(..10) {
+                    // Leave room for a dot after the number, so it doesn't run into the next one.
+                    0 | 1 if size - row.len() > 3 => {
+                        row += &rng.u32(1..1000).to_string();
+                        row.push('.');
+                    }
+                    2 => row.push(SYMBOLS[rng.usize(..SYMBOLS.len())]),
+                    _ => row.push('.'),
+                }
+            }
+            row
+        });
+        Some(generate::join_lines(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day03.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day03.parse(EXAMPLE).unwrap();
+        assert_eq!(Day03.part1(&input), Ok(Answer::Int(4361)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day03.parse(EXAMPLE).unwrap();
+        assert_eq!(Day03.part2(&input), Some(Ok(Answer::Int(467835))));
+    }
+
+    // A grid of part numbers, symbols and dots. Numbers have to fit in a u32, so keep rows short.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid("0123456789.*#+$/", 1..12, 1..10).prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day03, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day03.parse(&input);
+        }
+    }
+}
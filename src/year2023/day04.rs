@@ -0,0 +1,158 @@
+use std::collections::{HashMap, HashSet};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    // The number of winning numbers we have on each card.
+    type Input = Vec<usize>;
+
+    const TITLE: &'static str = "Scratchcards";
+    const NOTES: &'static str =
+        "Count each card's matches up front. Part 2 carries copies forward down the pile.";
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+        let mut matches: Vec<usize> = vec![];
+        for card in error::lines(input) {
+            let (_, numbers) = card.split_once(card.text, ":")?;
+            let (winning_numbers, our_numbers) = card.split_once(numbers, "|")?;
+            let winning_numbers = winning_numbers
+                .split_whitespace()
+                .map(|x| card.parse::<u32>(x, "a number"))
+                .collect::<Result<HashSet<u32>, ParseError>>()?;
+            let our_numbers = our_numbers
+                .split_whitespace()
+                .map(|x| card.parse::<u32>(x, "a number"))
+                .collect::<Result<HashSet<u32>, ParseError>>()?;
+            matches.push(our_numbers.intersection(&winning_numbers).count());
+        }
+        Ok(matches)
+    }
+
+    fn part1(&self, matches: &Vec<usize>) -> Result<Answer, SolveError> {
+        Ok(matches
+            .iter()
+            .filter(|m| **m > 0)
+            .map(|m| u32::pow(2, *m as u32 - 1))
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(&self, matches: &Vec<usize>) -> Option<Result<Answer, SolveError>> {
+        let mut card_counts: HashMap<usize, u32> = HashMap::new();
+        for (index, number_of_matches) in matches.iter().enumerate() {
+            let count_of_current_card: u32 = match card_counts.entry(index) {
+                std::collections::hash_map::Entry::Occupied(mut e) => {
+                    e.insert(e.get() + 1);
+                    *e.get()
+                }
+                std::collections::hash_map::Entry::Vacant(e) => {
+                    e.insert(1);
+                    1
+                }
+            };
+
+            for i in 0..*number_of_matches {
+                match card_counts.entry(index + 1 + i) {
+                    std::collections::hash_map::Entry::Occupied(mut e) => {
+                        e.insert(e.get() + count_of_current_card);
+                    }
+                    std::collections::hash_map::Entry::Vacant(e) => {
+                        e.insert(count_of_current_card);
+                    }
+                }
+            }
+        }
+        Some(Ok(card_counts.values().sum::<u32>().into()))
+    }
+
+    // Size is the number of cards. Cards win less than one card each on average, and never past
+    // the end of the table, so part 2's copies don't run away with themselves.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(200);
+        let spaced = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        let cards = (0..size).map(|card| {
+            let mut numbers = (1..100).collect::<Vec<u32>>();
+            rng.shuffle(&mut numbers);
+            let matches = match rng.u8(..6) {
+                0 => rng.usize(1..=10).min(size - card - 1),
+                _ => 0,
+            };
+            // The last `matches` winning numbers, then enough others to make 25.
+            let mut ours = numbers[10 - matches..35 - matches].to_vec();
+            rng.shuffle(&mut ours);
+            format!(
+                "Card {:>3}: {} | {}",
+                card + 1,
+                spaced(&numbers[..10]),
+                spaced(&ours)
+            )
+        });
+        Some(generate::join_lines(cards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day04.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day04.parse(EXAMPLE).unwrap();
+        assert_eq!(Day04.part1(&input), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day04.parse(EXAMPLE).unwrap();
+        assert_eq!(Day04.part2(&input), Some(Ok(Answer::Int(30))));
+    }
+
+    // Lines like `Card 1: 41 48 83 | 83 86 6`.
+    fn input() -> impl Strategy<Value = String> {
+        let numbers = |len| prop::collection::vec(1..100u32, len);
+        prop::collection::vec((numbers(1..6), numbers(1..10)), 1..10).prop_map(|cards| {
+            let lines = cards
+                .iter()
+                .enumerate()
+                .map(|(i, (winning, ours))| {
+                    format!(
+                        "Card {}: {} | {}",
+                        i + 1,
+                        grammar::join(winning, " "),
+                        grammar::join(ours, " ")
+                    )
+                })
+                .collect::<Vec<String>>();
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day04, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day04.parse(&input);
+        }
+    }
+}
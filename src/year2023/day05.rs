@@ -0,0 +1,419 @@
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub struct Range {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl Range {
+    fn contains(&self, value: u64) -> bool {
+        value >= self.start && value < self.end
+    }
+}
+
+pub struct Mapping {
+    pub source_range: Range,
+    pub dest_range: Range,
+}
+
+impl Mapping {
+    fn map(&self, value: u64) -> u64 {
+        if self.source_range.contains(value) {
+            value - self.source_range.start + self.dest_range.start
+        } else {
+            value
+        }
+    }
+
+    fn map_dest_to_source(&self, value: u64) -> u64 {
+        if self.dest_range.contains(value) {
+            value - self.dest_range.start + self.source_range.start
+        } else {
+            value
+        }
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub mappings: Vec<Vec<Mapping>>,
+}
+
+// Part 2 the other way round, to check the search against: push the ranges of seeds forwards
+// through each map, splitting them wherever a mapping starts or ends, and take the lowest
+// location at the end. Ranges are (start, end) with the end left out, like `Range`.
+pub fn lowest_location_forwards(almanac: &Almanac) -> Option<u64> {
+    let mut ranges = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|x| (x[0], x[0] + x[1]))
+        .collect::<Vec<(u64, u64)>>();
+    for mapping in &almanac.mappings {
+        let mut mapped = vec![];
+        while let Some((start, end)) = ranges.pop() {
+            if start >= end {
+                continue;
+            }
+            // Whatever covers the start of the range moves it up to the end of that mapping.
+            // Numbers that nothing covers stay where they are, up to where the next mapping
+            // starts.
+            match mapping
+                .iter()
+                .find(|inner_mapping| inner_mapping.source_range.contains(start))
+            {
+                Some(inner_mapping) => {
+                    let stop = end.min(inner_mapping.source_range.end);
+                    let mapped_start = inner_mapping.map(start);
+                    mapped.push((mapped_start, mapped_start + stop - start));
+                    ranges.push((stop, end));
+                }
+                None => {
+                    let stop = mapping
+                        .iter()
+                        .map(|inner_mapping| inner_mapping.source_range.start)
+                        .filter(|mapping_start| *mapping_start > start)
+                        .fold(end, u64::min);
+                    mapped.push((start, stop));
+                    ranges.push((stop, end));
+                }
+            }
+        }
+        ranges = mapped;
+    }
+    ranges
+        .iter()
+        .filter(|(start, end)| start < end)
+        .map(|(start, _)| *start)
+        .min()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    const NOTES: &'static str =
+        "Part 1 maps each seed forwards. Part 2 counts up from location 0, mapping back to a seed and skipping whole ranges at a time.";
+
+    fn parse(&self, input: &str) -> Result<Almanac, ParseError> {
+        let mut lines = error::lines(input);
+        let seeds_line = lines
+            .next()
+            .ok_or_else(|| ParseError::missing(1, "expected a list of seeds"))?;
+        let (_, seeds) = seeds_line.split_once(seeds_line.text, ":")?;
+        let seeds: Vec<u64> = seeds
+            .split_whitespace()
+            .map(|x| seeds_line.parse::<u64>(x, "a seed number"))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+        // Part 2 reads the seeds as pairs of a start and a length.
+        if seeds.is_empty() {
+            return Err(seeds_line.error(seeds_line.text, "expected a list of seeds"));
+        } else if !seeds.len().is_multiple_of(2) {
+            return Err(seeds_line.error(
+                seeds_line.text,
+                "expected pairs of seed numbers (a start and a length)",
+            ));
+        } else if seeds.chunks(2).any(|x| x[0].checked_add(x[1]).is_none()) {
+            return Err(seeds_line.error(seeds_line.text, "range of seeds is too big"));
+        }
+
+        let mut mappings: Vec<Vec<Mapping>> = vec![];
+        let mut current_mapping: Option<Vec<Mapping>> = None;
+        for line in lines {
+            if line.text.ends_with(':') {
+                current_mapping = Some(vec![]);
+                continue;
+            } else if line.text.is_empty() {
+                if let Some(current_mapping_vec) = current_mapping.take() {
+                    mappings.push(current_mapping_vec);
+                }
+                continue;
+            }
+
+            if let Some(ref mut current_mapping_vec) = current_mapping {
+                let numbers = line
+                    .text
+                    .split_whitespace()
+                    .map(|x| line.parse::<u64>(x, "a number"))
+                    .collect::<Result<Vec<u64>, ParseError>>()?;
+                if numbers.len() != 3 {
+                    return Err(line.error(line.text, "expected three numbers"));
+                }
+                let too_big = || line.error(line.text, "range is too big");
+                current_mapping_vec.push(Mapping {
+                    source_range: Range {
+                        start: numbers[1],
+                        end: numbers[1].checked_add(numbers[2]).ok_or_else(too_big)?,
+                    },
+                    dest_range: Range {
+                        start: numbers[0],
+                        end: numbers[0].checked_add(numbers[2]).ok_or_else(too_big)?,
+                    },
+                });
+            }
+        }
+        if let Some(current_mapping_vec) = current_mapping {
+            mappings.push(current_mapping_vec);
+        }
+
+        Ok(Almanac { seeds, mappings })
+    }
+
+    fn part1(&self, almanac: &Almanac) -> Result<Answer, SolveError> {
+        let mut locations: Vec<u64> = vec![];
+        for seed in &almanac.seeds {
+            let mut final_location = *seed;
+            for mapping in &almanac.mappings {
+                for inner_mapping in mapping {
+                    let mapped_location = inner_mapping.map(final_location);
+                    if final_location != mapped_location {
+                        final_location = mapped_location;
+                        break;
+                    }
+                }
+            }
+            locations.push(final_location);
+        }
+        Ok(locations.into_iter().min().unwrap().into())
+    }
+
+    fn part2(&self, almanac: &Almanac) -> Option<Result<Answer, SolveError>> {
+        let seed_ranges: Vec<Range> = almanac
+            .seeds
+            .chunks(2)
+            .map(|x| Range {
+                start: x[0],
+                end: x[0] + x[1],
+            })
+            .collect();
+
+        // This assumes there's a 1:1 mapping between seeds and locations, which seems heavily
+        // implied from the question.
+        //
+        // The plan here is to start from 0 and find the first location that maps back to a seed.
+        // In order to make this more efficient, we try to look at a range of numbers on each loop.
+        // We always shorten the length of this range so that it falls within a single mapping.
+        //
+        // Yeh, this code is very hard to reason about and just generally a bit shit.
+        let mut potential_location: u64 = 0;
+        while potential_location < 4294967295 {
+            let mut length_of_range: u64 = 4294967295;
+            let mut initial_seed: u64 = potential_location;
+            for mapping in almanac.mappings.iter().rev() {
+                let mut hit_mapping = false;
+                let mut might_need_to_reduce_current_range = false;
+                let mut closest_mapping_distance: u64 = 4294967295;
+                for inner_mapping in mapping {
+                    let mapped_seed = inner_mapping.map_dest_to_source(initial_seed);
+                    if initial_seed != mapped_seed {
+                        initial_seed = mapped_seed;
+                        length_of_range = std::cmp::min(
+                            length_of_range,
+                            inner_mapping.source_range.end - initial_seed,
+                        );
+                        hit_mapping = true;
+                        break;
+                    }
+                    if initial_seed < inner_mapping.dest_range.start {
+                        might_need_to_reduce_current_range = true;
+                        closest_mapping_distance = std::cmp::min(
+                            closest_mapping_distance,
+                            inner_mapping.dest_range.start - initial_seed,
+                        );
+                    }
+                }
+                if !hit_mapping && might_need_to_reduce_current_range {
+                    length_of_range = std::cmp::min(length_of_range, closest_mapping_distance);
+                }
+            }
+            // Every location in this range maps back to a seed in a line, so the first of them
+            // that's a seed we've got is the first seed range to start in there (or the one
+            // we're already in). It has to be the lowest of the lot, not just the first we try.
+            let first_offset = seed_ranges
+                .iter()
+                .filter(|seed_range| {
+                    seed_range.end > initial_seed
+                        && seed_range.start < initial_seed + length_of_range
+                })
+                .map(|seed_range| seed_range.start.saturating_sub(initial_seed))
+                .min();
+            if let Some(offset) = first_offset {
+                return Some(Ok((potential_location + offset).into()));
+            }
+            potential_location += length_of_range;
+        }
+
+        Some(Err(SolveError::new(
+            "no location up to 4294967295 maps back to one of the seeds",
+        )))
+    }
+
+    // Size is the number of ranges in each map. Each map cuts the numbers up into blocks and lays
+    // them out in a different order, so every seed ends up at its own location, which part 2
+    // relies on.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const LIMIT: u64 = 4_000_000_000;
+        const STAGES: [&str; 8] = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+        let size = size.unwrap_or(30).max(1);
+        let mut seeds = vec![];
+        for _ in 0..10 {
+            let start = rng.u64(..LIMIT - 1);
+            seeds.push(start);
+            seeds.push(rng.u64(1..=(LIMIT - start).min(LIMIT / 20)));
+        }
+        let seeds = seeds.iter().map(u64::to_string).collect::<Vec<String>>();
+        let mut lines = vec![format!("seeds: {}", seeds.join(" "))];
+        for stages in STAGES.windows(2) {
+            lines.push(String::new());
+            lines.push(format!("{}-to-{} map:", stages[0], stages[1]));
+            let mut cuts = (1..size).map(|_| rng.u64(1..LIMIT)).collect::<Vec<u64>>();
+            cuts.extend([0, LIMIT]);
+            cuts.sort();
+            cuts.dedup();
+            let mut blocks = cuts.windows(2).collect::<Vec<&[u64]>>();
+            rng.shuffle(&mut blocks);
+            let mut destination = 0;
+            for block in blocks {
+                let length = block[1] - block[0];
+                lines.push(format!("{} {} {}", destination, block[0], length));
+                destination += length;
+            }
+        }
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day05.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(Day05.part1(&input), Ok(Answer::Int(35)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(Day05.part2(&input), Some(Ok(Answer::Int(46))));
+    }
+
+    // Locations 10 to 19 come from seeds 30 to 39. Every location in that block maps back to a
+    // seed, so the search can skip through it in one go, but only as far as the seeds it came from
+    // go (the mapping's source range), not the locations (its destination range).
+    #[test]
+    fn test_part2_block_moved_down() {
+        let input = Day05
+            .parse("seeds: 30 1\n\nseed-to-location map:\n10 30 10\n30 10 10\n")
+            .unwrap();
+        assert_eq!(Day05.part2(&input), Some(Ok(Answer::Int(10))));
+    }
+
+    // What the differential harness cut its first disagreement down to. With no maps every seed
+    // is its own location, so the answer is the lowest seed range's start, not the first's.
+    #[test]
+    fn test_part2_lowest_seed_range_not_first() {
+        let input = Day05
+            .parse(
+                "seeds: 2410510428 989860 379036318 60821865 282059619 60583810 2643715464 \
+                 67706740 2875350217 142918796 1410599680 165066315 2729348348 55927997 \
+                 3921048694 69644328 23213129 47167286 3906739839 17223004\n",
+            )
+            .unwrap();
+        assert_eq!(Day05.part2(&input), Some(Ok(Answer::Int(23213129))));
+        assert_eq!(lowest_location_forwards(&input), Some(23213129));
+    }
+
+    #[test]
+    fn test_lowest_location_forwards() {
+        let input = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(lowest_location_forwards(&input), Some(46));
+    }
+
+    // Mappings right at the top of the range, where adding before subtracting overflows.
+    #[test]
+    fn test_mapping_near_the_top() {
+        let input = Day05
+            .parse("seeds: 100 105\n\nseed-to-location map:\n18446744073709551600 100 10\n")
+            .unwrap();
+        assert_eq!(
+            Day05.part1(&input),
+            Ok(Answer::from(18446744073709551600u64))
+        );
+
+        let mapping = Mapping {
+            source_range: Range {
+                start: u64::MAX - 10,
+                end: u64::MAX,
+            },
+            dest_range: Range {
+                start: u64::MAX - 20,
+                end: u64::MAX - 10,
+            },
+        };
+        assert_eq!(mapping.map(u64::MAX - 1), u64::MAX - 11);
+        assert_eq!(mapping.map_dest_to_source(u64::MAX - 11), u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_parse_bad_seeds() {
+        assert!(Day05.parse("seeds: 79 14 55\n").is_err());
+        assert!(Day05.parse("seeds:\n").is_err());
+        assert!(Day05.parse("seeds: 79 14\n").is_ok());
+    }
+
+    // A line of seeds, then blank-line separated maps of three numbers to a line.
+    fn input() -> impl Strategy<Value = String> {
+        let number = || 0..1u64 << 32;
+        let map = prop::collection::vec(prop::collection::vec(number(), 3), 1..4);
+        (
+            prop::collection::vec((number(), number()), 1..4),
+            prop::collection::vec(map, 1..5),
+        )
+            .prop_map(|(seeds, maps)| {
+                let seeds = seeds
+                    .iter()
+                    .flat_map(|&(start, length)| [start, length])
+                    .collect::<Vec<u64>>();
+                let mut lines = vec![format!("seeds: {}", grammar::join(&seeds, " "))];
+                for (i, map) in maps.iter().enumerate() {
+                    lines.push(String::new());
+                    lines.push(format!("thing{}-to-thing{} map:", i, i + 1));
+                    lines.extend(map.iter().map(|numbers| grammar::join(numbers, " ")));
+                }
+                generate::join_lines(&lines)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day05, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day05.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub struct Race {
+    pub time: u64,
+    pub record: u64,
+}
+
+impl Race {
+    pub fn number_of_winning_options(&self) -> u64 {
+        for time_spent_charging in 1..self.time {
+            if (self.time - time_spent_charging) * time_spent_charging > self.record {
+                // The winning options are obviously a symmetrical contiguous range
+                return self.time - 2 * time_spent_charging + 1;
+            }
+        }
+        0
+    }
+}
+
+// Smush all the numbers on a line together, ignoring the spaces, for part 2. None if there
+// aren't any numbers or they don't fit once they're smushed.
+fn concatenate(numbers: impl Iterator<Item = u64>) -> Option<u64> {
+    numbers
+        .map(|n| n.to_string())
+        .collect::<String>()
+        .parse::<u64>()
+        .ok()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+
+    const TITLE: &'static str = "Wait For It";
+    const NOTES: &'static str =
+        "Find the first charge time that wins; the winners are symmetric about the middle.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Race>, ParseError> {
+        let lines = error::lines(input)
+            .map(|line| {
+                let (_, numbers) = line.split_once(line.text, ":")?;
+                numbers
+                    .split_whitespace()
+                    .map(|x| line.parse::<u64>(x, "a number"))
+                    .collect::<Result<Vec<u64>, ParseError>>()
+            })
+            .collect::<Result<Vec<Vec<u64>>, ParseError>>()?;
+        if lines.len() != 2 {
+            return Err(ParseError::missing(
+                lines.len().min(2) + 1,
+                "expected a line of times and a line of distances",
+            ));
+        }
+        if lines[0].len() != lines[1].len() {
+            return Err(ParseError::missing(2, "expected a distance for every time"));
+        }
+        if lines[0].is_empty() {
+            return Err(ParseError::missing(1, "expected at least one race"));
+        }
+        for (i, numbers) in lines.iter().enumerate() {
+            if concatenate(numbers.iter().copied()).is_none() {
+                return Err(ParseError::missing(
+                    i + 1,
+                    "numbers are too big to run together for part 2",
+                ));
+            }
+        }
+        Ok(lines[0]
+            .iter()
+            .zip(lines[1].iter())
+            .map(|(time, record)| Race {
+                time: *time,
+                record: *record,
+            })
+            .collect())
+    }
+
+    fn part1(&self, races: &Vec<Race>) -> Result<Answer, SolveError> {
+        Ok(races
+            .iter()
+            .map(|r| r.number_of_winning_options())
+            .product::<u64>()
+            .into())
+    }
+
+    fn part2(&self, races: &Vec<Race>) -> Option<Result<Answer, SolveError>> {
+        Some(Ok(Race {
+            // Parsing checked these fit.
+            time: concatenate(races.iter().map(|r| r.time)).unwrap(),
+            record: concatenate(races.iter().map(|r| r.record)).unwrap(),
+        }
+        .number_of_winning_options()
+        .into()))
+    }
+
+    // Size is the number of races, up to four. Part 2 runs them all together into one long race,
+    // and the records won't fit in a u64 once there are more than the real input's four.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let (times, records): (Vec<String>, Vec<String>) = (0..size.unwrap_or(4).clamp(1, 4))
+            .map(|_| {
+                let time = rng.u64(7..100);
+                // Somebody held the button for less than half the race, so the record can be
+                // beaten.
+                let held = rng.u64(1..time / 2);
+                (
+                    format!("{:>4}", time),
+                    format!("{:>4}", held * (time - held)),
+                )
+            })
+            .unzip();
+        Some(generate::join_lines([
+            format!("Time:     {}", times.join(" ")),
+            format!("Distance: {}", records.join(" ")),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day06.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day06.parse(EXAMPLE).unwrap();
+        assert_eq!(Day06.part1(&input), Ok(Answer::Int(288)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day06.parse(EXAMPLE).unwrap();
+        assert_eq!(Day06.part2(&input), Some(Ok(Answer::Int(71503))));
+    }
+
+    #[test]
+    fn test_parse_no_races() {
+        assert!(Day06.parse("Time:\nDistance:\n").is_err());
+        assert!(Day06
+            .parse("Time: 99999999999 99999999999\nDistance: 1 2\n")
+            .is_err());
+    }
+
+    // A line of times and a line of record distances, one of each per race.
+    fn input() -> impl Strategy<Value = String> {
+        prop::collection::vec((0..100u64, 0..1000u64), 1..5).prop_map(|races| {
+            let (times, records): (Vec<u64>, Vec<u64>) = races.into_iter().unzip();
+            generate::join_lines(&[
+                format!("Time: {}", grammar::join(&times, "  ")),
+                format!("Distance: {}", grammar::join(&records, "  ")),
+            ])
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day06, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day06.parse(&input);
+        }
+    }
+}
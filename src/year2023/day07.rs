@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd)]
+pub enum HandType {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPair = 2,
+    ThreeOfAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    pub cards: Vec<u32>,
+    pub hand_type: HandType,
+    pub bid: u32,
+}
+
+impl Hand {
+    pub fn new(input_line: &str, wildcard_jacks: bool) -> Hand {
+        let split = input_line.split_whitespace().collect::<Vec<&str>>();
+        let cards = split[0]
+            .chars()
+            .map(|x| match x {
+                'T' => 10,
+                'J' => {
+                    if wildcard_jacks {
+                        1
+                    } else {
+                        11
+                    }
+                }
+                'Q' => 12,
+                'K' => 13,
+                'A' => 14,
+                _ => x.to_digit(10).unwrap(),
+            })
+            .collect::<Vec<u32>>();
+        let cards_set = cards
+            .iter()
+            .filter(|x| !wildcard_jacks || **x != 1)
+            .collect::<std::collections::HashSet<&u32>>();
+        let hand_type = match cards_set.len() {
+            5 => HandType::HighCard,
+            4 => HandType::OnePair,
+            3 => {
+                let mut hand_type = HandType::TwoPair;
+                for card in cards_set {
+                    if cards
+                        .iter()
+                        .filter(|&x| x == card || (wildcard_jacks && x == &1))
+                        .count()
+                        == 3
+                    {
+                        hand_type = HandType::ThreeOfAKind;
+                        break;
+                    }
+                }
+                hand_type
+            }
+            2 => {
+                let mut hand_type = HandType::FullHouse;
+                for card in cards_set {
+                    if cards
+                        .iter()
+                        .filter(|&x| x == card || (wildcard_jacks && x == &1))
+                        .count()
+                        == 4
+                    {
+                        hand_type = HandType::FourOfAKind;
+                        break;
+                    }
+                }
+                hand_type
+            }
+            1 => HandType::FiveOfAKind,
+            0 => {
+                if wildcard_jacks {
+                    HandType::FiveOfAKind
+                } else {
+                    panic!("Invalid number of cards")
+                }
+            }
+            _ => panic!("Invalid number of cards"),
+        };
+        Hand {
+            cards,
+            hand_type,
+            bid: split[1].parse::<u32>().unwrap(),
+        }
+    }
+}
+
+fn cmp_hands(a: &Hand, b: &Hand) -> Ordering {
+    match a.hand_type.cmp(&b.hand_type) {
+        Ordering::Greater => Ordering::Greater,
+        Ordering::Equal => {
+            for card_index in 0..5 {
+                match a.cards[card_index].cmp(&b.cards[card_index]) {
+                    Ordering::Greater => return Ordering::Greater,
+                    Ordering::Less => return Ordering::Less,
+                    Ordering::Equal => (),
+                }
+            }
+            Ordering::Equal
+        }
+        Ordering::Less => Ordering::Less,
+    }
+}
+
+// Sort the hands by strength and add up each bid multiplied by its rank.
+fn total_winnings(mut hands: Vec<Hand>) -> u32 {
+    hands.sort_by(cmp_hands);
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, x)| acc + x.bid * (i as u32 + 1))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    // How the cards are scored depends on the part, so hold off turning lines into hands. We do
+    // check that they'll make sensible hands though.
+    type Input = Vec<String>;
+
+    const TITLE: &'static str = "Camel Cards";
+    const NOTES: &'static str =
+        "Work out each hand's type from how many distinct cards it has (ignoring jokers in part 2), then sort.";
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        let mut lines: Vec<String> = vec![];
+        for line in error::lines(input) {
+            let (cards, bid) = line.split_once(line.text, " ")?;
+            if cards.chars().count() != 5 {
+                return Err(line.error(cards, "expected a hand of five cards"));
+            }
+            if let Some(index) = cards.find(|c| !"23456789TJQKA".contains(c)) {
+                return Err(line.error(&cards[index..], "expected a card (2-9, T, J, Q, K or A)"));
+            }
+            line.parse::<u32>(bid, "a bid")?;
+            lines.push(line.text.to_string());
+        }
+        Ok(lines)
+    }
+
+    fn part1(&self, lines: &Vec<String>) -> Result<Answer, SolveError> {
+        Ok(total_winnings(lines.iter().map(|x| Hand::new(x, false)).collect()).into())
+    }
+
+    fn part2(&self, lines: &Vec<String>) -> Option<Result<Answer, SolveError>> {
+        Some(Ok(total_winnings(
+            lines.iter().map(|x| Hand::new(x, true)).collect(),
+        )
+        .into()))
+    }
+
+    // Size is the number of hands, which are all different so that the ranking is unambiguous.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const CARDS: &[u8] = b"23456789TJQKA";
+        let size = size.unwrap_or(1000).min(CARDS.len().pow(5));
+        let mut hands = HashSet::new();
+        let mut lines = vec![];
+        while lines.len() < size {
+            let hand = (0..5)
+                .map(|_| CARDS[rng.usize(..CARDS.len())] as char)
+                .collect::<String>();
+            if hands.insert(hand.clone()) {
+                lines.push(format!("{} {}", hand, rng.u32(1..1000)));
+            }
+        }
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day07.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day07.parse(EXAMPLE).unwrap();
+        assert_eq!(Day07.part1(&input), Ok(Answer::Int(6440)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day07.parse(EXAMPLE).unwrap();
+        assert_eq!(Day07.part2(&input), Some(Ok(Answer::Int(5905))));
+    }
+
+    // Lines like `32T3K 765`.
+    fn input() -> impl Strategy<Value = String> {
+        prop::collection::vec((grammar::chars("23456789TJQKA", 5), 1..1000u32), 1..10).prop_map(
+            |hands| {
+                let lines = hands
+                    .iter()
+                    .map(|(cards, bid)| format!("{} {}", cards, bid))
+                    .collect::<Vec<String>>();
+                generate::join_lines(&lines)
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day07, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day07.parse(&input);
+        }
+    }
+}
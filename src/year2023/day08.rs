@@ -0,0 +1,208 @@
+use num_integer::lcm;
+use std::collections::HashMap;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub struct Network {
+    pub instructions: Vec<char>,
+    pub mappings: HashMap<String, (String, String)>,
+    pub starting_points: Vec<String>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    const TITLE: &'static str = "Haunted Wasteland";
+    const NOTES: &'static str =
+        "Follow the instructions. Part 2 takes the LCM of each ghost's steps to its first Z.";
+
+    fn parse(&self, input: &str) -> Result<Network, ParseError> {
+        let mut lines = error::lines(input);
+        let instructions_line = lines
+            .next()
+            .filter(|line| !line.text.is_empty())
+            .ok_or_else(|| ParseError::missing(1, "expected a list of instructions"))?;
+        if let Some(index) = instructions_line.text.find(|c| c != 'L' && c != 'R') {
+            return Err(instructions_line.error(
+                &instructions_line.text[index..],
+                "expected an instruction (L or R)",
+            ));
+        }
+        let instructions: Vec<char> = instructions_line.text.chars().collect();
+        let mut mappings: HashMap<String, (String, String)> = HashMap::new();
+        let mut starting_points: Vec<String> = Vec::new();
+        for line in lines.skip(1) {
+            let (node, rhs) = line.split_once(line.text, " = ")?;
+            let rhs = rhs
+                .strip_prefix('(')
+                .and_then(|rhs| rhs.strip_suffix(')'))
+                .ok_or_else(|| line.error(rhs, "expected (LEFT, RIGHT)"))?;
+            let (left, right) = line.split_once(rhs, ", ")?;
+            mappings.insert(node.to_string(), (left.to_string(), right.to_string()));
+            if node.ends_with('A') {
+                starting_points.push(node.to_string());
+            }
+        }
+        Ok(Network {
+            instructions,
+            mappings,
+            starting_points,
+        })
+    }
+
+    fn part1(&self, network: &Network) -> Result<Answer, SolveError> {
+        let mut current_location = "AAA".to_string();
+        let mut step_count = 0;
+        loop {
+            for instruction in &network.instructions {
+                step_count += 1;
+                if instruction == &'L' {
+                    current_location = network.mappings[&current_location].0.to_string();
+                } else {
+                    current_location = network.mappings[&current_location].1.to_string();
+                }
+                if current_location == "ZZZ" {
+                    return Ok(step_count.into());
+                }
+            }
+        }
+    }
+
+    // Calculate each path independently and take the least common multiple of the step counts.
+    fn part2(&self, network: &Network) -> Option<Result<Answer, SolveError>> {
+        let mut step_counts: Vec<u64> = vec![];
+        for location in &network.starting_points {
+            let mut step_count = 0;
+            let mut new_location = location.to_string();
+            'outer: loop {
+                for instruction in &network.instructions {
+                    step_count += 1;
+                    if instruction == &'L' {
+                        new_location = network.mappings[&new_location].0.to_string();
+                    } else {
+                        new_location = network.mappings[&new_location].1.to_string();
+                    }
+
+                    if new_location.ends_with('Z') {
+                        step_counts.push(step_count);
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        Some(Ok(step_counts.iter().fold(1, |a, b| lcm(a, *b)).into()))
+    }
+
+    // Size is roughly how many steps each ghost takes to get round its loop. As in the real
+    // input, the loops are a prime number of steps long whichever way the ghosts turn, so the
+    // LCM in part 2 works, and the first ghost walks from AAA to ZZZ.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const GHOSTS: usize = 6;
+        // Names that don't end in A or Z, so nothing else looks like a start or an end.
+        const ALPHABET: &str = "BCDEFGHIJKLMNOPQRSTUVWXY";
+        let instructions = (0..rng.usize(10..300))
+            .map(|_| if rng.bool() { 'L' } else { 'R' })
+            .collect::<String>();
+        let mut prefixes = vec!["AA".to_string()];
+        prefixes.extend(generate::names(rng, GHOSTS - 1, 2, ALPHABET, &["AA"]));
+        let mut nodes: Vec<(String, String, String)> = vec![];
+        let mut taken: Vec<String> = vec![];
+        let mut period = size.unwrap_or(100).max(2) as u64 / 2;
+        for prefix in prefixes {
+            period = generate::next_prime(period + 1);
+            // Two nodes for each step round the loop. Either way leads to one of the next pair.
+            let taken_names = taken.iter().map(String::as_str).collect::<Vec<&str>>();
+            let levels = generate::names(rng, 2 * (period as usize - 1), 3, ALPHABET, &taken_names)
+                .chunks(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .collect::<Vec<(String, String)>>();
+            taken.extend(levels.iter().flat_map(|(a, b)| [a.clone(), b.clone()]));
+            let start = format!("{}A", prefix);
+            let end = match start.as_str() {
+                "AAA" => "ZZZ".to_string(),
+                _ => format!("{}Z", prefix),
+            };
+            let next = |rng: &mut Rng, (a, b): &(String, String)| match rng.bool() {
+                true => (a.clone(), b.clone()),
+                false => (b.clone(), a.clone()),
+            };
+            let (left, right) = next(rng, &levels[0]);
+            nodes.push((start, left, right));
+            let (left, right) = next(rng, &levels[0]);
+            nodes.push((end.clone(), left, right));
+            for (level, (a, b)) in levels.iter().enumerate() {
+                for node in [a, b] {
+                    let (left, right) = match levels.get(level + 1) {
+                        Some(pair) => next(rng, pair),
+                        None => (end.clone(), end.clone()),
+                    };
+                    nodes.push((node.clone(), left, right));
+                }
+            }
+        }
+        rng.shuffle(&mut nodes);
+        let mut lines = vec![instructions, String::new()];
+        lines.extend(
+            nodes
+                .into_iter()
+                .map(|(node, left, right)| format!("{} = ({}, {})", node, left, right)),
+        );
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day08.txt");
+    const PART2_EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day08_part2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day08.parse(EXAMPLE).unwrap();
+        assert_eq!(Day08.part1(&input), Ok(Answer::Int(6)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day08.parse(PART2_EXAMPLE).unwrap();
+        assert_eq!(Day08.part2(&input), Some(Ok(Answer::Int(6))));
+    }
+
+    // A line of Ls and Rs, a blank line, then nodes like `AAA = (BBB, CCC)`.
+    fn input() -> impl Strategy<Value = String> {
+        let node = || grammar::chars("ABCXZ12", 3);
+        let nodes = prop::collection::vec((node(), node(), node()), 1..10);
+        (grammar::chars("LR", 1..10), nodes).prop_map(|(instructions, nodes)| {
+            let mut lines = vec![instructions, String::new()];
+            lines.extend(
+                nodes
+                    .iter()
+                    .map(|(node, left, right)| format!("{} = ({}, {})", node, left, right)),
+            );
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day08, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day08.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+fn find_next_number(sequence: &[i32]) -> i32 {
+    let differences = sequence
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect::<Vec<i32>>();
+    if differences.iter().all(|x| *x == 0) {
+        *sequence.last().unwrap()
+    } else {
+        sequence.last().unwrap() + find_next_number(&differences)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    const TITLE: &'static str = "Mirage Maintenance";
+    const NOTES: &'static str =
+        "Take differences recursively until they're all zero. Part 2 extrapolates the reversed history.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        error::lines(input)
+            .map(|line| {
+                line.text
+                    .split_whitespace()
+                    .map(|x| line.parse::<i32>(x, "a number"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(&self, sequences: &Vec<Vec<i32>>) -> Result<Answer, SolveError> {
+        Ok(sequences
+            .iter()
+            .map(|sequence| find_next_number(sequence))
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(&self, sequences: &Vec<Vec<i32>>) -> Option<Result<Answer, SolveError>> {
+        Some(Ok(sequences
+            .iter()
+            .map(|sequence| find_next_number(&sequence.iter().rev().copied().collect::<Vec<i32>>()))
+            .sum::<i32>()
+            .into()))
+    }
+
+    // Size is the number of histories. Each is 21 values of a random polynomial, kept small
+    // enough that the sums in both parts fit in an i32.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let histories = (0..size.unwrap_or(200)).map(|_| {
+            let coefficients = (0..=rng.usize(0..=4))
+                .map(|_| rng.i64(-9..=9))
+                .collect::<Vec<i64>>();
+            (0..21)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                        .to_string()
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        });
+        Some(generate::join_lines(histories))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day09.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day09.parse(EXAMPLE).unwrap();
+        assert_eq!(Day09.part1(&input), Ok(Answer::Int(114)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day09.parse(EXAMPLE).unwrap();
+        assert_eq!(Day09.part2(&input), Some(Ok(Answer::Int(2))));
+    }
+
+    // Lines of numbers, some of them negative.
+    fn input() -> impl Strategy<Value = String> {
+        let history = prop::collection::vec(-100..100i32, 1..10)
+            .prop_map(|numbers| grammar::join(&numbers, " "));
+        prop::collection::vec(history, 1..10).prop_map(|lines| generate::join_lines(&lines))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day09, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day09.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use std::collections::{HashMap, HashSet};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+pub struct Maze {
+    pub grid: HashMap<Coord, char>,
+    pub start: Coord,
+    pub height: usize,
+    pub width: usize,
+}
+
+// Find the loop that passes through `start`.
+//
+// Returns the set of points in the loop, plus the type of pipe that must be hiding under the S.
+fn find_loop(start: &Coord, grid: &HashMap<Coord, char>) -> (HashSet<Coord>, char) {
+    // Figure out the initial set of points a path could start from
+    let mut plausible_start_neighbours: HashMap<Coord, Direction> = HashMap::new();
+    let up_connector: Option<Coord> = get_up_connector(start, grid);
+    if let Some(up_connector) = up_connector {
+        plausible_start_neighbours.insert(up_connector, Direction::Down);
+    }
+    let down_connector: Option<Coord> = get_down_connector(start, grid);
+    if let Some(down_connector) = down_connector {
+        plausible_start_neighbours.insert(down_connector, Direction::Up);
+    }
+    let left_connector: Option<Coord> = get_left_connector(start, grid);
+    if let Some(left_connector) = left_connector {
+        plausible_start_neighbours.insert(left_connector, Direction::Right);
+    }
+    let right_connector: Option<Coord> = get_right_connector(start, grid);
+    if let Some(right_connector) = right_connector {
+        plausible_start_neighbours.insert(right_connector, Direction::Left);
+    }
+
+    // For each of the plausible start points, try to find a path that connects back to the start.
+    'outer: for start_neighbour in plausible_start_neighbours.keys() {
+        let mut path: HashSet<Coord> = HashSet::new();
+        path.insert(start.clone());
+        path.insert(start_neighbour.clone());
+
+        let mut next_point_in_path: Coord = start_neighbour.clone();
+        // We track which direction we came from, so we don't go back the way we came.
+        let mut next_prev_direction = plausible_start_neighbours
+            .get(start_neighbour)
+            .unwrap()
+            .clone();
+        loop {
+            let (connector, prev_direction): (Option<Coord>, Direction) =
+                get_connecting_point(&next_point_in_path, grid, &next_prev_direction);
+            if let Some(connector) = connector {
+                next_point_in_path = connector;
+                next_prev_direction = prev_direction.clone();
+
+                if path.contains(&next_point_in_path) {
+                    panic!("Hit incomplete loop - this shouldn't be possible");
+                }
+                path.insert(next_point_in_path.clone());
+
+                if plausible_start_neighbours.contains_key(&next_point_in_path) {
+                    // We've found a path that connects back to the start.
+
+                    // Unbelievably tedious code to figure out what type of pipe S is.
+                    let mut directions_set: HashSet<Direction> = HashSet::new();
+                    directions_set.insert(
+                        plausible_start_neighbours
+                            .get(&next_point_in_path)
+                            .unwrap()
+                            .clone(),
+                    );
+                    directions_set.insert(
+                        plausible_start_neighbours
+                            .get(start_neighbour)
+                            .unwrap()
+                            .clone(),
+                    );
+                    let start_pipe = if directions_set.contains(&Direction::Up)
+                        && directions_set.contains(&Direction::Down)
+                    {
+                        '|'
+                    } else if directions_set.contains(&Direction::Left)
+                        && directions_set.contains(&Direction::Right)
+                    {
+                        '-'
+                    } else if directions_set.contains(&Direction::Down)
+                        && directions_set.contains(&Direction::Left)
+                    {
+                        'L'
+                    } else if directions_set.contains(&Direction::Down)
+                        && directions_set.contains(&Direction::Right)
+                    {
+                        'J'
+                    } else if directions_set.contains(&Direction::Up)
+                        && directions_set.contains(&Direction::Left)
+                    {
+                        'F'
+                    } else if directions_set.contains(&Direction::Up)
+                        && directions_set.contains(&Direction::Right)
+                    {
+                        '7'
+                    } else {
+                        'S'
+                    };
+                    return (path, start_pipe);
+                }
+            } else {
+                // We've hit a dead end, move on to the next potential path.
+                continue 'outer;
+            }
+        }
+    }
+    panic!("No loop found through the start point");
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+
+    const TITLE: &'static str = "Pipe Maze";
+    const NOTES: &'static str =
+        "Follow the pipes from S round the loop. Part 2 counts loop crossings from each tile to the edge.";
+
+    fn parse(&self, input: &str) -> Result<Maze, ParseError> {
+        let mut grid: HashMap<Coord, char> = HashMap::new();
+        let mut start: Option<Coord> = None;
+        let lines = error::grid(input)?;
+        let height = lines.len();
+        let width = lines[0].text.chars().count();
+
+        // Build grid.
+        for (row, line) in lines.iter().enumerate() {
+            for (col, c) in line.text.chars().enumerate() {
+                match c {
+                    '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' => (),
+                    'S' if start.is_none() => start = Some(Coord { row, col }),
+                    'S' => return Err(line.error_at(col, "expected only one start point")),
+                    _ => return Err(line.error_at(col, "expected a pipe, . or S")),
+                }
+                grid.insert(Coord { row, col }, c);
+            }
+        }
+        Ok(Maze {
+            grid,
+            start: start.ok_or_else(|| ParseError::missing(1, "expected a start point"))?,
+            height,
+            width,
+        })
+    }
+
+    fn part1(&self, maze: &Maze) -> Result<Answer, SolveError> {
+        let (path, _) = find_loop(&maze.start, &maze.grid);
+        Ok((path.len() / 2).into())
+    }
+
+    fn part2(&self, maze: &Maze) -> Option<Result<Answer, SolveError>> {
+        let (path, start_pipe) = find_loop(&maze.start, &maze.grid);
+        let mut grid = maze.grid.clone();
+        grid.insert(maze.start.clone(), start_pipe);
+
+        // For each point in the grid, count the number of times we have to cross the path to get
+        // to the boundary.
+        let mut number_of_points_inside_path = 0;
+        for row in 0..maze.height {
+            for col in 0..maze.width {
+                let mut number_of_boundaries_crossed = 0;
+                if !path.contains(&Coord { row, col }) {
+                    // Erm... Best not to dwell on this code too much. We need to track bends in a
+                    // way that is both hard for me to think about and also apparently hard for me
+                    // to code.
+                    let mut found_f: usize = 0;
+                    let mut found_l: usize = 0;
+                    let mut found_7: usize = 0;
+                    let mut found_j: usize = 0;
+                    for new_row in 0..row {
+                        if path.contains(&Coord { row: new_row, col }) {
+                            let point = grid.get(&Coord { row: new_row, col }).unwrap();
+                            if point == &'-' {
+                                number_of_boundaries_crossed += 1;
+                            } else if point == &'F' {
+                                if found_j > 0 {
+                                    number_of_boundaries_crossed += 1;
+                                    found_j -= 1;
+                                } else {
+                                    found_f += 1;
+                                }
+                            } else if point == &'L' {
+                                if found_7 > 0 {
+                                    number_of_boundaries_crossed += 1;
+                                    found_7 -= 1;
+                                } else {
+                                    found_l += 1;
+                                }
+                            } else if point == &'7' {
+                                if found_l > 0 {
+                                    number_of_boundaries_crossed += 1;
+                                    found_l -= 1;
+                                } else {
+                                    found_7 += 1;
+                                }
+                            } else if point == &'J' {
+                                if found_f > 0 {
+                                    number_of_boundaries_crossed += 1;
+                                    found_f -= 1;
+                                } else {
+                                    found_j += 1;
+                                }
+                            }
+                        }
+                    }
+                    if number_of_boundaries_crossed % 2 == 1 {
+                        number_of_points_inside_path += 1;
+                    }
+                }
+            }
+        }
+        Some(Ok(number_of_points_inside_path.into()))
+    }
+
+    // Size is the width (and height) of the grid. The loop is the outline of a random tree, so it
+    // winds about and encloses pockets of tiles, and everything off the loop is junk pipe.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+        let size = size.unwrap_or(140).max(3);
+        // The tree covers somewhere between half and all of the grid.
+        let cells = rng.usize(size * size / 18..=size * size / 9);
+        let outline = generate::random_loop(rng, size, size, cells);
+        let mut grid = (0..size)
+            .map(|_| (0..size).map(|_| JUNK[rng.usize(..JUNK.len())]).collect())
+            .collect::<Vec<Vec<char>>>();
+        for (i, &(row, col)) in outline.iter().enumerate() {
+            let previous = outline[(i + outline.len() - 1) % outline.len()];
+            let next = outline[(i + 1) % outline.len()];
+            let joins = |(r, c): (usize, usize)| [previous, next].contains(&(r, c));
+            grid[row][col] = match (
+                row > 0 && joins((row - 1, col)),
+                joins((row + 1, col)),
+                col > 0 && joins((row, col - 1)),
+                joins((row, col + 1)),
+            ) {
+                (true, true, _, _) => '|',
+                (_, _, true, true) => '-',
+                (true, _, _, true) => 'L',
+                (true, _, true, _) => 'J',
+                (_, true, true, _) => '7',
+                _ => 'F',
+            };
+        }
+        // Start anywhere on the loop, and clear away junk next to it that might look like it
+        // joins on.
+        let on_loop = outline.iter().collect::<HashSet<&(usize, usize)>>();
+        let (row, col) = outline[rng.usize(..outline.len())];
+        grid[row][col] = 'S';
+        for (r, c) in [(row + 1, col), (row, col + 1)]
+            .into_iter()
+            .chain(row.checked_sub(1).map(|r| (r, col)))
+            .chain(col.checked_sub(1).map(|c| (row, c)))
+        {
+            if r < size && c < size && !on_loop.contains(&(r, c)) {
+                grid[r][c] = '.';
+            }
+        }
+        Some(generate::join_lines(
+            grid.into_iter()
+                .map(|row| row.into_iter().collect::<String>()),
+        ))
+    }
+}
+
+// Search for a point in `grid` that directly connects to `coord`, excluding in the direction
+// indicated by the `exclude_direction`.
+//
+// Returns the connecting point and the direction of `coord` *_from_* that connecting point.
+pub fn get_connecting_point(
+    coord: &Coord,
+    grid: &HashMap<Coord, char>,
+    exclude_direction: &Direction,
+) -> (Option<Coord>, Direction) {
+    let current_coord = grid.get(coord).unwrap_or(&'.');
+    if !matches!(exclude_direction, Direction::Up)
+        && (current_coord == &'|' || current_coord == &'J' || current_coord == &'L')
+    {
+        return (get_up_connector(coord, grid), Direction::Down);
+    }
+    if !matches!(exclude_direction, Direction::Down)
+        && (current_coord == &'|' || current_coord == &'7' || current_coord == &'F')
+    {
+        return (get_down_connector(coord, grid), Direction::Up);
+    }
+    if !matches!(exclude_direction, Direction::Left)
+        && (current_coord == &'-' || current_coord == &'7' || current_coord == &'J')
+    {
+        return (get_left_connector(coord, grid), Direction::Right);
+    }
+    if !matches!(exclude_direction, Direction::Right)
+        && (current_coord == &'-' || current_coord == &'F' || current_coord == &'L')
+    {
+        return (get_right_connector(coord, grid), Direction::Left);
+    }
+    (None, Direction::Up)
+}
+
+pub fn get_up_connector(coord: &Coord, grid: &HashMap<Coord, char>) -> Option<Coord> {
+    // Nothing can connect from off the top of the grid.
+    let up_coord = Coord {
+        row: coord.row.checked_sub(1)?,
+        col: coord.col,
+    };
+    if check_point_connects(&up_coord, grid, &['|', '7', 'F']) {
+        return Some(up_coord);
+    }
+    None
+}
+
+pub fn get_down_connector(coord: &Coord, grid: &HashMap<Coord, char>) -> Option<Coord> {
+    let down_coord = Coord {
+        row: coord.row + 1,
+        col: coord.col,
+    };
+    if check_point_connects(&down_coord, grid, &['|', 'J', 'L']) {
+        return Some(down_coord);
+    }
+    None
+}
+
+pub fn get_left_connector(coord: &Coord, grid: &HashMap<Coord, char>) -> Option<Coord> {
+    // Nothing can connect from off the left of the grid.
+    let left_coord = Coord {
+        row: coord.row,
+        col: coord.col.checked_sub(1)?,
+    };
+    if check_point_connects(&left_coord, grid, &['-', 'F', 'L']) {
+        return Some(left_coord);
+    }
+    None
+}
+
+pub fn get_right_connector(coord: &Coord, grid: &HashMap<Coord, char>) -> Option<Coord> {
+    let right_coord = Coord {
+        row: coord.row,
+        col: coord.col + 1,
+    };
+    if check_point_connects(&right_coord, grid, &['-', '7', 'J']) {
+        return Some(right_coord);
+    }
+    None
+}
+
+pub fn check_point_connects(
+    coord: &Coord,
+    grid: &HashMap<Coord, char>,
+    match_list: &[char],
+) -> bool {
+    if match_list.contains(grid.get(coord).unwrap_or(&'.')) {
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day10.txt");
+    const PART2_EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day10_part2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day10.parse(EXAMPLE).unwrap();
+        assert_eq!(Day10.part1(&input), Ok(Answer::Int(8)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day10.parse(PART2_EXAMPLE).unwrap();
+        assert_eq!(Day10.part2(&input), Some(Ok(Answer::Int(10))));
+    }
+
+    // Nothing can connect to the start from above or to the left of it when it's in the top
+    // left corner.
+    #[test]
+    fn test_start_in_corner() {
+        let input = Day10.parse("S-7\n|.|\nL-J\n").unwrap();
+        assert_eq!(Day10.part1(&input), Ok(Answer::Int(4)));
+        assert_eq!(Day10.part2(&input), Some(Ok(Answer::Int(1))));
+    }
+
+    // A grid of pipes and ground with one start point.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid_with_one("|-LJ7F.", 'S', 1..10, 1..10)
+            .prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day10, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day10.parse(&input);
+        }
+    }
+}
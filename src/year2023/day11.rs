@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Point {
+    Galaxy,
+    Empty,
+}
+
+fn expand_rows_in_universe(
+    base_universe: &Vec<Vec<Point>>,
+    expansion_factor: usize,
+) -> Vec<Vec<Point>> {
+    let mut expanded_universe: Vec<Vec<Point>> = vec![];
+    for line in base_universe {
+        expanded_universe.push(line.clone());
+        if line.iter().all(|x| matches!(x, Point::Empty)) {
+            for _ in 0..expansion_factor {
+                expanded_universe.push(line.clone());
+            }
+        }
+    }
+    expanded_universe
+}
+
+fn expand_universe(base_universe: &Vec<Vec<Point>>, expansion_factor: usize) -> Vec<Vec<Point>> {
+    let len = base_universe[0].len();
+    let mut half_expanded_universe: Vec<Vec<Point>> =
+        expand_rows_in_universe(base_universe, expansion_factor);
+
+    // Transpose universe.
+    let mut iters: Vec<_> = half_expanded_universe
+        .into_iter()
+        .map(|n| n.into_iter())
+        .collect();
+    half_expanded_universe = (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .map(|n| n.next().unwrap())
+                .collect::<Vec<Point>>()
+        })
+        .collect();
+
+    expand_rows_in_universe(&half_expanded_universe, expansion_factor)
+}
+
+fn find_galaxies(universe: &[Vec<Point>]) -> HashSet<(usize, usize)> {
+    let mut galaxies: HashSet<(usize, usize)> = HashSet::new();
+    for (i, row) in universe.iter().enumerate() {
+        for (j, col) in row.iter().enumerate() {
+            if matches!(col, Point::Galaxy) {
+                galaxies.insert((i, j));
+            }
+        }
+    }
+    galaxies
+}
+
+fn count_distances(galaxies: &HashSet<(usize, usize)>) -> isize {
+    let mut processed_galaxies: HashSet<(usize, usize)> = HashSet::new();
+    let mut sum = 0;
+    for galaxy in galaxies {
+        for galaxy_to_compare in galaxies {
+            if processed_galaxies.contains(galaxy_to_compare) {
+                continue;
+            } else {
+                sum += (galaxy.0 as isize - galaxy_to_compare.0 as isize).abs()
+                    + (galaxy.1 as isize - galaxy_to_compare.1 as isize).abs();
+            }
+        }
+        processed_galaxies.insert(*galaxy);
+    }
+    sum
+}
+
+fn sum_of_distances(base_universe: &Vec<Vec<Point>>, expansion_factor: usize) -> isize {
+    let expanded_universe: Vec<Vec<Point>> = expand_universe(base_universe, expansion_factor);
+    let expanded_galaxies: HashSet<(usize, usize)> = find_galaxies(&expanded_universe);
+    count_distances(&expanded_galaxies)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Vec<Point>>;
+
+    const TITLE: &'static str = "Cosmic Expansion";
+    const NOTES: &'static str =
+        "Sum the distances between galaxies. Part 2 extrapolates from expanding once and twice.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+        let mut base_universe: Vec<Vec<Point>> = vec![];
+        for line in error::grid(input)? {
+            let mut row: Vec<Point> = vec![];
+            for (col, c) in line.text.chars().enumerate() {
+                match c {
+                    '.' => row.push(Point::Empty),
+                    '#' => row.push(Point::Galaxy),
+                    _ => return Err(line.error_at(col, "expected . or #")),
+                }
+            }
+            base_universe.push(row);
+        }
+        Ok(base_universe)
+    }
+
+    fn part1(&self, base_universe: &Vec<Vec<Point>>) -> Result<Answer, SolveError> {
+        Ok(sum_of_distances(base_universe, 1).into())
+    }
+
+    // Expanding the universe a million times over isn't going to fly. Expand it once and twice,
+    // and extrapolate from the difference.
+    fn part2(&self, base_universe: &Vec<Vec<Point>>) -> Option<Result<Answer, SolveError>> {
+        let once_expanded_distances = sum_of_distances(base_universe, 1);
+        let twice_expanded_distances = sum_of_distances(base_universe, 2);
+        Some(Ok((once_expanded_distances
+            + (twice_expanded_distances - once_expanded_distances)
+                * 999998)
+            .into()))
+    }
+
+    // Size is the width (and height) of the image. About one row and column in ten is empty, so
+    // there's something to expand.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(140);
+        let empty_rows = (0..size)
+            .filter(|_| rng.u8(..10) == 0)
+            .collect::<HashSet<usize>>();
+        let empty_cols = (0..size)
+            .filter(|_| rng.u8(..10) == 0)
+            .collect::<HashSet<usize>>();
+        let rows = (0..size).map(|row| {
+            (0..size)
+                .map(|col| {
+                    match !empty_rows.contains(&row)
+                        && !empty_cols.contains(&col)
+                        && rng.u8(..50) == 0
+                    {
+                        true => '#',
+                        false => '.',
+                    }
+                })
+                .collect::<String>()
+        });
+        Some(generate::join_lines(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day11.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part1(&input), Ok(Answer::Int(374)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part2(&input), Some(Ok(Answer::Int(82000210))));
+    }
+
+    // A grid of galaxies and space.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid(".#", 1..10, 1..10).prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day11, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day11.parse(&input);
+        }
+    }
+}
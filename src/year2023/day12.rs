@@ -1,6 +1,11 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
 
 type Cache = HashMap<(Vec<char>, Vec<u64>), usize>;
 
@@ -161,24 +166,97 @@ fn process_line_x5(line: &str, cache: &mut Cache) -> usize {
     count_possibilities(&row_chars, &arrangement, cache)
 }
 
-pub(crate) fn day12() {
-    let f: File = File::open("data/day12.txt").unwrap();
-    let reader: BufReader<File> = BufReader::new(f);
-    let lines = reader.lines().collect::<io::Result<Vec<String>>>().unwrap();
-    let mut part1_sum: usize = 0;
-    let mut part2_sum: usize = 0;
-    let mut cache: Cache = HashMap::new();
-    for line in lines {
-        part1_sum += process_line(&line, &mut cache);
-        part2_sum += process_line_x5(&line, &mut cache);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<String>;
+
+    const TITLE: &'static str = "Hot Springs";
+    const NOTES: &'static str =
+        "Recursively count arrangements, memoised on what's left of the row and the groups.";
+
+    // The rows are processed straight from the text, but check they're well formed first.
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        let mut lines: Vec<String> = vec![];
+        for line in error::lines(input) {
+            if line.text.split_whitespace().count() != 2 {
+                return Err(line.error(line.text, "expected springs and group sizes"));
+            }
+            let (springs, groups) = line.split_once(line.text.trim(), " ")?;
+            if let Some(index) = springs.find(|c| !".#?".contains(c)) {
+                return Err(line.error(&springs[index..], "expected ., # or ?"));
+            }
+            for group in groups.split(',') {
+                line.parse::<u64>(group, "a group size")?;
+            }
+            lines.push(line.text.to_string());
+        }
+        Ok(lines)
+    }
+
+    fn part1(&self, lines: &Vec<String>) -> Result<Answer, SolveError> {
+        let mut cache: Cache = HashMap::new();
+        Ok(lines
+            .iter()
+            .map(|line| process_line(line, &mut cache))
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self, lines: &Vec<String>) -> Option<Result<Answer, SolveError>> {
+        let mut cache: Cache = HashMap::new();
+        Some(Ok(lines
+            .iter()
+            .map(|line| process_line_x5(line, &mut cache))
+            .sum::<usize>()
+            .into()))
+    }
+
+    // Size is the number of rows. Each row is made by laying out its groups of damaged springs and
+    // then forgetting about half of what we know.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let rows = (0..size.unwrap_or(1000)).map(|_| {
+            let groups = (0..rng.usize(1..=6))
+                .map(|_| rng.usize(1..=5))
+                .collect::<Vec<usize>>();
+            let mut springs = ".".repeat(rng.usize(0..=2));
+            for (i, group) in groups.iter().enumerate() {
+                if i > 0 {
+                    springs += &".".repeat(rng.usize(1..=3));
+                }
+                springs += &"#".repeat(*group);
+            }
+            springs += &".".repeat(rng.usize(0..=2));
+            let springs = springs
+                .chars()
+                .map(|c| if rng.bool() { '?' } else { c })
+                .collect::<String>();
+            let groups = groups.iter().map(usize::to_string).collect::<Vec<String>>();
+            format!("{} {}", springs, groups.join(","))
+        });
+        Some(generate::join_lines(rows))
     }
-    println!("Day 12 part 1: {}", part1_sum);
-    println!("Day 12 part 2: {}", part2_sum);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day12.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part1(&input), Ok(Answer::Int(21)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part2(&input), Some(Ok(Answer::Int(525152))));
+    }
 
     #[test]
     fn test_process_line_x5() {
@@ -204,4 +282,34 @@ mod tests {
             506250,
         );
     }
+
+    // Lines like `???.### 1,1,3`.
+    fn input() -> impl Strategy<Value = String> {
+        prop::collection::vec(
+            (
+                grammar::chars(".#?", 1..20),
+                prop::collection::vec(1..5u64, 1..5),
+            ),
+            1..10,
+        )
+        .prop_map(|rows| {
+            let lines = rows
+                .iter()
+                .map(|(springs, groups)| format!("{} {}", springs, grammar::join(groups, ",")))
+                .collect::<Vec<String>>();
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day12, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day12.parse(&input);
+        }
+    }
 }
@@ -0,0 +1,253 @@
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub fn get_col_reflection(pattern: &[String], previous: Option<usize>) -> Option<usize> {
+    let mut transposed_pattern: Vec<String> = vec![];
+    for i in 0..pattern[0].len() {
+        let mut new_row = String::new();
+        for c in pattern {
+            new_row.push(c.chars().nth(i).unwrap());
+        }
+        transposed_pattern.push(new_row);
+    }
+    get_row_reflection(&transposed_pattern, previous)
+}
+
+pub fn get_row_reflection(pattern: &[String], previous: Option<usize>) -> Option<usize> {
+    for i in 1..pattern.len() {
+        // Reflection between row i-1 and row i.
+        let mut candidate = true;
+        for j in 0..(std::cmp::min(i, pattern.len() - i)) {
+            if pattern[i - j - 1] != pattern[i + j] {
+                candidate = false;
+                break;
+            }
+        }
+        if candidate && Some(i) != previous {
+            return Some(i);
+        }
+    }
+    None
+}
+
+// Find the original line of reflection in a pattern, returned as (column, row) - only one of
+// which will be set.
+fn find_reflection(pattern: &[String]) -> (Option<usize>, Option<usize>) {
+    if let Some(val) = get_col_reflection(pattern, None) {
+        (Some(val), None)
+    } else if let Some(val) = get_row_reflection(pattern, None) {
+        (None, Some(val))
+    } else {
+        panic!("No part 1 reflection found");
+    }
+}
+
+// The number of cells that don't match across each possible line of reflection between rows.
+fn row_reflection_differences(pattern: &[Vec<bool>]) -> Vec<usize> {
+    (1..pattern.len())
+        .map(|i| {
+            (0..i.min(pattern.len() - i))
+                .map(|j| {
+                    pattern[i - j - 1]
+                        .iter()
+                        .zip(&pattern[i + j])
+                        .filter(|(a, b)| a != b)
+                        .count()
+                })
+                .sum()
+        })
+        .collect()
+}
+
+fn transpose(pattern: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..pattern[0].len())
+        .map(|col| pattern.iter().map(|row| row[col]).collect())
+        .collect()
+}
+
+// A random pattern with a perfect reflection between two columns and a reflection between two
+// rows that's out by one cell, turned on its side half the time. We build both reflections
+// perfectly, then smudge a cell that only the row reflection covers.
+fn smudged_pattern(rng: &mut Rng) -> Vec<String> {
+    loop {
+        let (height, width) = (rng.usize(5..=17), rng.usize(5..=17));
+        let mut pattern = (0..height)
+            .map(|_| (0..width).map(|_| rng.bool()).collect::<Vec<bool>>())
+            .collect::<Vec<Vec<bool>>>();
+        let column = rng.usize(1..width);
+        for row in pattern.iter_mut() {
+            for col in (2 * column).saturating_sub(width)..column {
+                row[2 * column - 1 - col] = row[col];
+            }
+        }
+        let line = rng.usize(1..height);
+        for row in (2 * line).saturating_sub(height)..line {
+            pattern[2 * line - 1 - row] = pattern[row].clone();
+        }
+        let rows = (2 * line).saturating_sub(height)..(2 * line).min(height);
+        let cols = (0..width)
+            .filter(|&col| col < (2 * column).saturating_sub(width) || col >= 2 * column)
+            .collect::<Vec<usize>>();
+        if cols.is_empty() {
+            continue;
+        }
+        let (row, col) = (rng.usize(rows), cols[rng.usize(..cols.len())]);
+        pattern[row][col] = !pattern[row][col];
+        if rng.bool() {
+            pattern = transpose(&pattern);
+        }
+
+        // Random cells can line up into reflections of their own, so check there's nothing else.
+        let mut differences = row_reflection_differences(&pattern);
+        differences.extend(row_reflection_differences(&transpose(&pattern)));
+        if differences.iter().filter(|d| **d == 0).count() == 1
+            && differences.iter().filter(|d| **d == 1).count() == 1
+        {
+            return pattern
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|rock| if rock { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Vec<String>>;
+
+    const TITLE: &'static str = "Point of Incidence";
+    const NOTES: &'static str =
+        "Try every line of reflection. Part 2 flips each cell in turn until a new line appears.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+        let mut patterns: Vec<Vec<String>> = vec![];
+        let mut current_pattern: Vec<String> = vec![];
+        for line in error::lines(input) {
+            if line.text.is_empty() {
+                if current_pattern.is_empty() {
+                    return Err(line.error(line.text, "expected a pattern"));
+                }
+                patterns.push(current_pattern);
+                current_pattern = vec![];
+                continue;
+            }
+            if let Some(index) = line.text.find(|c| c != '#' && c != '.') {
+                return Err(line.error(&line.text[index..], "expected # or ."));
+            }
+            if let Some(first) = current_pattern.first() {
+                if first.len() != line.text.len() {
+                    return Err(line.error(
+                        line.text,
+                        format!("expected every line to be {} characters wide", first.len()),
+                    ));
+                }
+            }
+            current_pattern.push(line.text.to_string());
+        }
+        if !current_pattern.is_empty() {
+            patterns.push(current_pattern);
+        }
+        Ok(patterns)
+    }
+
+    fn part1(&self, patterns: &Vec<Vec<String>>) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for pattern in patterns {
+            let (col_val, row_val) = find_reflection(pattern);
+            sum += col_val.unwrap_or(0) + 100 * row_val.unwrap_or(0);
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, patterns: &Vec<Vec<String>>) -> Option<Result<Answer, SolveError>> {
+        let mut sum = 0;
+        for current_pattern in patterns {
+            let (orig_col_val, orig_row_val) = find_reflection(current_pattern);
+            let mut part2_found = false;
+            'outer: for i in 0..current_pattern.len() {
+                for j in 0..current_pattern[0].len() {
+                    let mut fixed_pattern: Vec<String> = current_pattern.clone();
+                    if fixed_pattern[i].get(j..j + 1).unwrap() == "." {
+                        fixed_pattern[i].replace_range(j..j + 1, "#");
+                    } else {
+                        fixed_pattern[i].replace_range(j..j + 1, ".");
+                    }
+                    if let Some(val) = get_col_reflection(&fixed_pattern, orig_col_val) {
+                        sum += val;
+                        part2_found = true;
+                        break 'outer;
+                    } else if let Some(val) = get_row_reflection(&fixed_pattern, orig_row_val) {
+                        sum += 100 * val;
+                        part2_found = true;
+                        break 'outer;
+                    }
+                }
+            }
+            if !part2_found {
+                panic!("No part 2 reflection found");
+            }
+        }
+        Some(Ok(sum.into()))
+    }
+
+    // Size is the number of patterns. Each has exactly one line of reflection, and exactly one
+    // other that's out by a single smudge, as part 2 expects.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let mut lines = vec![];
+        for i in 0..size.unwrap_or(100) {
+            if i > 0 {
+                lines.push(String::new());
+            }
+            lines.extend(smudged_pattern(rng));
+        }
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day13.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part1(&input), Ok(Answer::Int(405)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part2(&input), Some(Ok(Answer::Int(400))));
+    }
+
+    // Patterns of ash and rocks, separated by blank lines.
+    fn input() -> impl Strategy<Value = String> {
+        prop::collection::vec(grammar::grid(".#", 1..8, 1..8), 1..4)
+            .prop_map(|patterns| generate::join_lines(patterns.join(&String::new())))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day13, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day13.parse(&input);
+        }
+    }
+}
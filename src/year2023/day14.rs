@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rock {
+    RoundRock,
+    SquareRock,
+}
+
+fn tilt_north(grid: &mut HashMap<(usize, usize), Rock>, height: usize, width: usize) {
+    for row in 0..height {
+        for col in 0..width {
+            if grid.get(&(row, col)) == Some(&Rock::RoundRock) {
+                let mut new_row = 0;
+                for previous_row in 0..row {
+                    if grid.get(&(previous_row, col)).is_some() {
+                        new_row = previous_row + 1;
+                    }
+                }
+                grid.remove(&(row, col));
+                grid.insert((new_row, col), Rock::RoundRock);
+            }
+        }
+    }
+}
+
+fn tilt_west(grid: &mut HashMap<(usize, usize), Rock>, height: usize, width: usize) {
+    for row in 0..height {
+        for col in 0..width {
+            if grid.get(&(row, col)) == Some(&Rock::RoundRock) {
+                let mut new_col = 0;
+                for previous_col in 0..col {
+                    if grid.get(&(row, previous_col)).is_some() {
+                        new_col = previous_col + 1;
+                    }
+                }
+                grid.remove(&(row, col));
+                grid.insert((row, new_col), Rock::RoundRock);
+            }
+        }
+    }
+}
+
+fn tilt_east(grid: &mut HashMap<(usize, usize), Rock>, height: usize, width: usize) {
+    for row in 0..height {
+        for col in (0..width).rev() {
+            if grid.get(&(row, col)) == Some(&Rock::RoundRock) {
+                let mut new_col = width - 1;
+                for previous_col in (col + 1..width).rev() {
+                    if grid.get(&(row, previous_col)).is_some() {
+                        new_col = previous_col - 1;
+                    }
+                }
+                grid.remove(&(row, col));
+                grid.insert((row, new_col), Rock::RoundRock);
+            }
+        }
+    }
+}
+
+fn tilt_south(grid: &mut HashMap<(usize, usize), Rock>, height: usize, width: usize) {
+    for row in (0..height).rev() {
+        for col in 0..width {
+            if grid.get(&(row, col)) == Some(&Rock::RoundRock) {
+                let mut new_row = height - 1;
+                for previous_row in (row + 1..height).rev() {
+                    if grid.get(&(previous_row, col)).is_some() {
+                        new_row = previous_row - 1;
+                    }
+                }
+                grid.remove(&(row, col));
+                grid.insert((new_row, col), Rock::RoundRock);
+            }
+        }
+    }
+}
+
+fn perform_cycle(grid: &mut HashMap<(usize, usize), Rock>, height: usize, width: usize) {
+    tilt_north(grid, height, width);
+    tilt_west(grid, height, width);
+    tilt_south(grid, height, width);
+    tilt_east(grid, height, width);
+}
+
+fn calculate_total_load_on_the_north_support_beams(
+    grid: &HashMap<(usize, usize), Rock>,
+    height: usize,
+) -> usize {
+    grid.iter()
+        .filter_map(|(key, val)| {
+            if val == &Rock::RoundRock {
+                Some(height - key.0)
+            } else {
+                None
+            }
+        })
+        .sum::<usize>()
+}
+
+fn render_grid(grid: &HashMap<(usize, usize), Rock>, height: usize, width: usize) -> String {
+    let mut rendered = String::new();
+    for row in 0..height {
+        rendered.push('\n');
+        for col in 0..width {
+            match grid.get(&(row, col)) {
+                Some(Rock::RoundRock) => rendered.push('O'),
+                Some(Rock::SquareRock) => rendered.push('#'),
+                None => rendered.push('.'),
+            }
+        }
+    }
+    rendered
+}
+
+pub struct Platform {
+    pub grid: HashMap<(usize, usize), Rock>,
+    pub height: usize,
+    pub width: usize,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+    const NOTES: &'static str =
+        "Tilt the rocks one by one. Part 2 spots when the platform repeats and skips ahead.";
+
+    fn parse(&self, input: &str) -> Result<Platform, ParseError> {
+        let lines = error::grid(input)?;
+        let height = lines.len();
+        let width = lines[0].text.chars().count();
+        let mut grid: HashMap<(usize, usize), Rock> = HashMap::new();
+        for (row, line) in lines.iter().enumerate() {
+            for (col, c) in line.text.chars().enumerate() {
+                match c {
+                    '.' => (),
+                    'O' => {
+                        grid.insert((row, col), Rock::RoundRock);
+                    }
+                    '#' => {
+                        grid.insert((row, col), Rock::SquareRock);
+                    }
+                    _ => return Err(line.error_at(col, "expected ., O or #")),
+                }
+            }
+        }
+        Ok(Platform {
+            grid,
+            height,
+            width,
+        })
+    }
+
+    fn part1(&self, platform: &Platform) -> Result<Answer, SolveError> {
+        let mut grid = platform.grid.clone();
+        tilt_north(&mut grid, platform.height, platform.width);
+        Ok(calculate_total_load_on_the_north_support_beams(&grid, platform.height).into())
+    }
+
+    fn part2(&self, platform: &Platform) -> Option<Result<Answer, SolveError>> {
+        let (height, width) = (platform.height, platform.width);
+        let mut grid = platform.grid.clone();
+
+        // Let's find a loop.
+        let mut previous_states: HashMap<Vec<(usize, usize)>, usize> = HashMap::new();
+        let mut current_iteration = 0;
+        loop {
+            current_iteration += 1;
+            perform_cycle(&mut grid, height, width);
+            let mut keys = grid
+                .keys()
+                .cloned()
+                .collect::<Vec<(usize, usize)>>()
+                .clone();
+            keys.sort();
+            if previous_states.contains_key(&keys) {
+                // We've found a loop! Calculate how big the loop is and fast forward as far as we
+                // can without going past 1000000000.
+                let previous_iteration = previous_states.get(&keys).unwrap();
+                let cycle_length = current_iteration - previous_iteration;
+                log::debug!(
+                    first = previous_iteration,
+                    again = current_iteration,
+                    length = cycle_length;
+                    "platform repeats"
+                );
+                log::trace!(
+                    "platform when it repeats:{}",
+                    render_grid(&grid, height, width)
+                );
+                let skip_to = cycle_length * ((1_000_000_000 - current_iteration) / cycle_length)
+                    + current_iteration;
+                // Now loop until 1000000000 iterations.
+                for _ in skip_to..1_000_000_000 {
+                    perform_cycle(&mut grid, height, width);
+                }
+                return Some(Ok(calculate_total_load_on_the_north_support_beams(
+                    &grid, height,
+                )
+                .into()));
+            }
+            previous_states.insert(keys, current_iteration);
+        }
+    }
+
+    // Size is the width (and height) of the platform.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(100);
+        let rows = (0..size).map(|_| {
+            (0..size)
+                .map(|_| match rng.u8(..30) {
+                    0..=5 => 'O',
+                    6..=10 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        });
+        Some(generate::join_lines(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day14.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day14.parse(EXAMPLE).unwrap();
+        assert_eq!(Day14.part1(&input), Ok(Answer::Int(136)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day14.parse(EXAMPLE).unwrap();
+        assert_eq!(Day14.part2(&input), Some(Ok(Answer::Int(64))));
+    }
+
+    // A grid of round rocks, cube rocks and empty space.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid(".O#", 1..10, 1..10).prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day14, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day14.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub struct Lens {
+    label: String,
+    focal_length: u32,
+}
+
+fn hash(s: &str) -> u32 {
+    let mut current_value = 0;
+    for c in s.chars() {
+        current_value += c as u32;
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+
+    const TITLE: &'static str = "Lens Library";
+    const NOTES: &'static str = "HASH each step. Part 2 keeps a list of lenses per box.";
+
+    // Part 1 hashes the steps exactly as written, so keep them as text, but check that part 2
+    // will be able to make sense of them.
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        let line = error::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::missing(1, "expected a list of steps"))?;
+        let mut steps: Vec<String> = vec![];
+        for step in line.text.split(',') {
+            if let Some((_, focal_length)) = step.split_once('=') {
+                focal_length
+                    .parse::<u32>()
+                    .map_err(|_| line.error(focal_length, "expected a focal length"))?;
+            } else if !step.contains('-') {
+                return Err(line.error(step, "expected a step like label=N or label-"));
+            }
+            steps.push(step.to_string());
+        }
+        Ok(steps)
+    }
+
+    fn part1(&self, steps: &Vec<String>) -> Result<Answer, SolveError> {
+        Ok(steps.iter().map(|step| hash(step)).sum::<u32>().into())
+    }
+
+    fn part2(&self, steps: &Vec<String>) -> Option<Result<Answer, SolveError>> {
+        let mut boxes: HashMap<u32, Vec<Lens>> = HashMap::new();
+        for step in steps {
+            if step.contains('=') {
+                let split = step.split('=').collect::<Vec<&str>>();
+                let lens_label = split[0];
+                let box_label = hash(lens_label);
+                let focal_length = split[1].parse::<u32>().unwrap();
+                let lens = Lens {
+                    label: lens_label.to_string(),
+                    focal_length,
+                };
+                if let Some(lenses) = boxes.get_mut(&box_label) {
+                    if let Some(p) = lenses.iter().position(|l| l.label == lens_label) {
+                        lenses[p] = lens;
+                    } else {
+                        lenses.push(lens);
+                    }
+                } else {
+                    let lenses = vec![lens];
+                    boxes.insert(box_label, lenses);
+                }
+            } else if step.contains('-') {
+                let split = step.split('-').collect::<Vec<&str>>();
+                let lens_label = split[0];
+                let box_label = hash(lens_label);
+                if let Some(lenses) = boxes.get_mut(&box_label) {
+                    if let Some(p) = lenses.iter().position(|l| l.label == lens_label) {
+                        lenses.remove(p);
+                    }
+                }
+            } else {
+                panic!("Invalid step: {}", step);
+            }
+        }
+
+        let mut sum = 0;
+        for (box_label, lenses) in boxes {
+            for (index, lens) in lenses.iter().enumerate() {
+                sum += (box_label + 1) * (index as u32 + 1) * lens.focal_length;
+            }
+        }
+        Some(Ok(sum.into()))
+    }
+
+    // Size is the number of steps. Labels come from a pool a tenth that size, so lenses get
+    // replaced and taken out as well as put in.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(4000);
+        let labels = generate::names(rng, size / 10 + 1, 2, "abcdefghijklmnopqrstuvwxyz", &[]);
+        let steps = (0..size)
+            .map(|_| {
+                let label = &labels[rng.usize(..labels.len())];
+                match rng.u8(..3) {
+                    0 => format!("{}-", label),
+                    _ => format!("{}={}", label, rng.u8(1..=9)),
+                }
+            })
+            .collect::<Vec<String>>();
+        Some(format!("{}\n", steps.join(",")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day15.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day15.parse(EXAMPLE).unwrap();
+        assert_eq!(Day15.part1(&input), Ok(Answer::Int(1320)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day15.parse(EXAMPLE).unwrap();
+        assert_eq!(Day15.part2(&input), Some(Ok(Answer::Int(145))));
+    }
+
+    // A single line of steps like `rn=1` or `cm-`.
+    fn input() -> impl Strategy<Value = String> {
+        let label = grammar::chars("abcdefghijklmnopqrstuvwxyz", 1..6);
+        let step =
+            (label, prop::option::of(1..10u32)).prop_map(
+                |(label, focal_length)| match focal_length {
+                    Some(focal_length) => format!("{}={}", label, focal_length),
+                    None => format!("{}-", label),
+                },
+            );
+        prop::collection::vec(step, 1..10).prop_map(|steps| format!("{}\n", steps.join(",")))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day15, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day15.parse(&input);
+        }
+    }
+}
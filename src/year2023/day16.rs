@@ -0,0 +1,280 @@
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+pub enum Point {
+    Empty,
+    ForwardMirror,
+    BackMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Debug, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Debug, Copy)]
+pub struct BeamOfLight {
+    position: (usize, usize),
+    direction: Direction,
+}
+
+// Given a beam of light `beam`, determine the co-ordinates of it's next location, if it exists.
+fn find_next_coord(beam: &BeamOfLight, grid: &[Vec<Point>]) -> Option<(usize, usize)> {
+    match beam.direction {
+        Direction::Up => {
+            if beam.position.0 > 0 {
+                return Some((beam.position.0 - 1, beam.position.1));
+            }
+        }
+        Direction::Down => {
+            if beam.position.0 < grid.len() - 1 {
+                return Some((beam.position.0 + 1, beam.position.1));
+            }
+        }
+        Direction::Left => {
+            if beam.position.1 > 0 {
+                return Some((beam.position.0, beam.position.1 - 1));
+            }
+        }
+        Direction::Right => {
+            if beam.position.1 < grid[0].len() - 1 {
+                return Some((beam.position.0, beam.position.1 + 1));
+            }
+        }
+    }
+    None
+}
+
+// Insert this new beam of light into the cache. If it's not already in the cache, also insert it
+// into the set of unprocessed light beams.
+fn insert_beam(
+    beam: BeamOfLight,
+    unprocessed_light_beams: &mut HashSet<BeamOfLight>,
+    cache: &mut HashSet<BeamOfLight>,
+) {
+    if cache.insert(beam) {
+        unprocessed_light_beams.insert(beam);
+    }
+}
+
+// A beam of light enters the co-ordinate at `location` from direction `entry_direction`.
+//
+// Return a vector of beams of light that come out the other side.
+fn process_light_beam(
+    location: (usize, usize),
+    entry_direction: Direction,
+    grid: &[Vec<Point>],
+) -> Vec<BeamOfLight> {
+    match grid[location.0][location.1] {
+        Point::Empty => {
+            vec![BeamOfLight {
+                position: location,
+                direction: entry_direction,
+            }]
+        }
+        Point::ForwardMirror => {
+            let exit_direction = match entry_direction {
+                Direction::Up => Direction::Right,
+                Direction::Down => Direction::Left,
+                Direction::Left => Direction::Down,
+                Direction::Right => Direction::Up,
+            };
+            vec![BeamOfLight {
+                position: location,
+                direction: exit_direction,
+            }]
+        }
+        Point::BackMirror => {
+            let exit_direction = match entry_direction {
+                Direction::Up => Direction::Left,
+                Direction::Down => Direction::Right,
+                Direction::Left => Direction::Up,
+                Direction::Right => Direction::Down,
+            };
+            vec![BeamOfLight {
+                position: location,
+                direction: exit_direction,
+            }]
+        }
+        Point::HorizontalSplitter => match entry_direction {
+            Direction::Up | Direction::Down => {
+                vec![
+                    BeamOfLight {
+                        position: location,
+                        direction: Direction::Left,
+                    },
+                    BeamOfLight {
+                        position: location,
+                        direction: Direction::Right,
+                    },
+                ]
+            }
+            Direction::Left | Direction::Right => {
+                vec![BeamOfLight {
+                    position: location,
+                    direction: entry_direction,
+                }]
+            }
+        },
+        Point::VerticalSplitter => match entry_direction {
+            Direction::Left | Direction::Right => {
+                vec![
+                    BeamOfLight {
+                        position: location,
+                        direction: Direction::Up,
+                    },
+                    BeamOfLight {
+                        position: location,
+                        direction: Direction::Down,
+                    },
+                ]
+            }
+            Direction::Up | Direction::Down => {
+                vec![BeamOfLight {
+                    position: location,
+                    direction: entry_direction,
+                }]
+            }
+        },
+    }
+}
+
+// Fire a beam of light into the grid at `entry` and count how many tiles end up energized.
+fn count_energized_tiles(entry: (usize, usize, Direction), grid: &[Vec<Point>]) -> usize {
+    let mut energized_tiles: HashSet<(usize, usize)> = HashSet::new();
+    energized_tiles.insert((entry.0, entry.1));
+    let mut beams_of_light: HashSet<BeamOfLight> = HashSet::new();
+    let mut cache: HashSet<BeamOfLight> = HashSet::new();
+    for new_beam in process_light_beam((entry.0, entry.1), entry.2, grid) {
+        insert_beam(new_beam, &mut beams_of_light, &mut cache);
+    }
+    loop {
+        let beam = *beams_of_light.iter().next().unwrap();
+        beams_of_light.remove(&beam);
+        let next_coord = find_next_coord(&beam, grid);
+        if let Some(next_coord) = next_coord {
+            energized_tiles.insert(next_coord);
+            for new_beam in process_light_beam(next_coord, beam.direction, grid) {
+                insert_beam(new_beam, &mut beams_of_light, &mut cache);
+            }
+        }
+        if beams_of_light.is_empty() {
+            break;
+        }
+    }
+    energized_tiles.len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Vec<Point>>;
+
+    const TITLE: &'static str = "The Floor Will Be Lava";
+    const NOTES: &'static str =
+        "Follow every beam, remembering the ones we've seen so loops end. Part 2 tries every entry point.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+        let mut grid: Vec<Vec<Point>> = vec![];
+        for (row, line) in error::grid(input)?.iter().enumerate() {
+            grid.push(vec![]);
+            for (col, c) in line.text.chars().enumerate() {
+                grid[row].push(match c {
+                    '.' => Point::Empty,
+                    '/' => Point::ForwardMirror,
+                    '\\' => Point::BackMirror,
+                    '-' => Point::HorizontalSplitter,
+                    '|' => Point::VerticalSplitter,
+                    _ => return Err(line.error_at(col, "expected ., /, \\, - or |")),
+                });
+            }
+        }
+        Ok(grid)
+    }
+
+    fn part1(&self, grid: &Vec<Vec<Point>>) -> Result<Answer, SolveError> {
+        Ok(count_energized_tiles((0, 0, Direction::Right), grid).into())
+    }
+
+    fn part2(&self, grid: &Vec<Vec<Point>>) -> Option<Result<Answer, SolveError>> {
+        let mut entry_points: Vec<(usize, usize, Direction)> = vec![];
+        for i in 0..grid.len() {
+            entry_points.push((i, 0, Direction::Right));
+            entry_points.push((i, grid[0].len() - 1, Direction::Left));
+        }
+        for i in 0..grid[0].len() {
+            entry_points.push((0, i, Direction::Down));
+            entry_points.push((grid.len() - 1, i, Direction::Up));
+        }
+        let mut max_energized_tiles = 0;
+        for entry in entry_points {
+            max_energized_tiles =
+                std::cmp::max(max_energized_tiles, count_energized_tiles(entry, grid));
+        }
+        Some(Ok(max_energized_tiles.into()))
+    }
+
+    // Size is the width (and height) of the contraption.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const DEVICES: [char; 4] = ['/', '\\', '-', '|'];
+        let size = size.unwrap_or(110);
+        let rows = (0..size).map(|_| {
+            (0..size)
+                .map(|_| match rng.u8(..8) {
+                    0 => DEVICES[rng.usize(..DEVICES.len())],
+                    _ => '.',
+                })
+                .collect::<String>()
+        });
+        Some(generate::join_lines(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day16.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day16.parse(EXAMPLE).unwrap();
+        assert_eq!(Day16.part1(&input), Ok(Answer::Int(46)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day16.parse(EXAMPLE).unwrap();
+        assert_eq!(Day16.part2(&input), Some(Ok(Answer::Int(51))));
+    }
+
+    // A grid of mirrors, splitters and empty space.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid(".\\/-|", 1..10, 1..10).prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day16, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day16.parse(&input);
+        }
+    }
+}
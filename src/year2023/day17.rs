@@ -1,10 +1,15 @@
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -44,10 +49,10 @@ impl PartialOrd for State {
 
 // Each node is represented as a `usize`, for a shorter implementation.
 #[derive(Debug)]
-struct Edge {
-    node: usize,
-    cost: usize,
-    direction: Direction,
+pub struct Edge {
+    pub node: usize,
+    pub cost: usize,
+    pub direction: Direction,
 }
 
 // Dijkstra's shortest path algorithm.
@@ -83,6 +88,8 @@ fn shortest_path(
         .collect();
 
     let mut heap = BinaryHeap::new();
+    // How much work it took, for the logs.
+    let (mut popped, mut peak_heap) = (0, 1);
 
     heap.push(State {
         cost: 0,
@@ -99,8 +106,16 @@ fn shortest_path(
         distance_travelled_in_stright_line,
     }) = heap.pop()
     {
+        popped += 1;
         if position == goal {
             if distance_travelled_in_stright_line >= straight_line_constraints.0 {
+                log::debug!(
+                    cost = cost,
+                    popped = popped,
+                    left = heap.len(),
+                    peak = peak_heap;
+                    "reached the goal"
+                );
                 return Some(cost);
             }
             continue;
@@ -160,6 +175,7 @@ fn shortest_path(
                     .unwrap()
             {
                 heap.push(next);
+                peak_heap = peak_heap.max(heap.len());
                 // Relaxation, we have now found a better way
                 *dist[next.position]
                     .get_mut(&(edge.direction, new_distance_travelled_in_stright_line))
@@ -214,32 +230,111 @@ fn build_graph(
     }
 }
 
-pub(crate) fn day17() {
-    let f: File = File::open("data/day17.txt").unwrap();
-    let reader: BufReader<File> = BufReader::new(f);
-    let input_data: Vec<String> = reader.lines().collect::<io::Result<Vec<String>>>().unwrap();
+pub struct City {
+    pub graph: Vec<Vec<Edge>>,
+    pub start_point: usize,
+    pub end_point: usize,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = City;
+
+    const TITLE: &'static str = "Clumsy Crucible";
+    const NOTES: &'static str =
+        "Dijkstra over position, direction and how far we've gone in a straight line.";
+
+    fn parse(&self, input: &str) -> Result<City, ParseError> {
+        let input_data = error::grid(input)?;
 
-    let number_of_rows: usize = input_data.len();
-    let number_of_columns: usize = input_data.get(0).unwrap().len();
-    let mut grid: Vec<Vec<usize>> = vec![vec![0; number_of_columns]; number_of_rows];
-    let start_point: usize = 0;
-    let end_point: usize = number_of_columns * number_of_rows - 1;
+        let number_of_rows: usize = input_data.len();
+        let number_of_columns: usize = input_data[0].text.chars().count();
+        let mut grid: Vec<Vec<usize>> = vec![vec![0; number_of_columns]; number_of_rows];
 
-    for (i, line) in input_data.iter().enumerate() {
-        for (j, point) in line.chars().enumerate() {
-            grid[i][j] = usize::try_from(point.to_digit(10).unwrap()).unwrap();
+        for (i, line) in input_data.iter().enumerate() {
+            for (j, point) in line.text.chars().enumerate() {
+                grid[i][j] = point
+                    .to_digit(10)
+                    .ok_or_else(|| line.error_at(j, "expected a heat loss digit"))?
+                    as usize;
+            }
         }
+
+        let mut graph: Vec<Vec<Edge>> = Vec::new();
+        build_graph(&grid, number_of_rows, number_of_columns, &mut graph);
+        Ok(City {
+            graph,
+            start_point: 0,
+            end_point: number_of_columns * number_of_rows - 1,
+        })
+    }
+
+    fn part1(&self, city: &City) -> Result<Answer, SolveError> {
+        Ok(
+            shortest_path(&city.graph, city.start_point, city.end_point, (1, 3))
+                .unwrap()
+                .into(),
+        )
+    }
+
+    fn part2(&self, city: &City) -> Option<Result<Answer, SolveError>> {
+        Some(Ok(shortest_path(
+            &city.graph,
+            city.start_point,
+            city.end_point,
+            (4, 10),
+        )
+        .unwrap()
+        .into()))
     }
 
-    let mut graph: Vec<Vec<Edge>> = Vec::new();
-    build_graph(&grid, number_of_rows, number_of_columns, &mut graph);
-
-    println!(
-        "Day 17 part 1: {}",
-        shortest_path(&graph, start_point, end_point, (1, 3)).unwrap()
-    );
-    println!(
-        "Day 17 part 2: {}",
-        shortest_path(&graph, start_point, end_point, (4, 10)).unwrap()
-    );
+    // Size is the width (and height) of the city.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(141);
+        let rows = (0..size).map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.u8(1..=9)))
+                .collect::<String>()
+        });
+        Some(generate::join_lines(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day17.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day17.parse(EXAMPLE).unwrap();
+        assert_eq!(Day17.part1(&input), Ok(Answer::Int(102)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day17.parse(EXAMPLE).unwrap();
+        assert_eq!(Day17.part2(&input), Some(Ok(Answer::Int(94))));
+    }
+
+    // A grid of heat loss digits.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid("123456789", 1..10, 1..10).prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day17, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day17.parse(&input);
+        }
+    }
 }
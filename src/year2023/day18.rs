@@ -0,0 +1,335 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub min_x: isize,
+    pub max_x: isize,
+    pub min_y: isize,
+    pub max_y: isize,
+}
+
+fn in_path(path: &HashSet<Range>, x: isize, y: isize) -> bool {
+    for range in path {
+        if x >= range.min_x && x <= range.max_x && y >= range.min_y && y <= range.max_y {
+            return true;
+        }
+    }
+    false
+}
+
+// Slightly random function that returns the path instances in column y, and a the number of
+// contiguous columns that are identical to column y, starting at column y and going right.
+//
+// The path instances are returned as a vector of tuples, where the first element is the start
+// of a path instance, and the second element is the end of the path instance.
+fn find_path_in_column_y(path: &HashSet<Range>, y: isize) -> (Vec<(isize, isize)>, isize) {
+    let mut path_instances: Vec<(isize, isize)> = Vec::new();
+    let mut max_y_that_looks_identical = isize::MAX;
+    for range in path {
+        if (y < range.max_y && y > range.min_y) || (y == range.max_y && y == range.min_y) {
+            max_y_that_looks_identical = std::cmp::min(max_y_that_looks_identical, range.max_y);
+            path_instances.push((range.min_x, range.max_x));
+        }
+    }
+    path_instances.sort_by_key(|a| a.0);
+
+    for range in path {
+        if range.min_y == range.max_y
+            && range.min_y > y
+            && range.min_y <= max_y_that_looks_identical
+        {
+            max_y_that_looks_identical = range.min_y - 1;
+        }
+    }
+    if max_y_that_looks_identical == isize::MAX {
+        panic!("No path either in column y, or to the right of column y - what are we doing here?!")
+    }
+    (path_instances, 1 + max_y_that_looks_identical - y)
+}
+
+fn lagoon_interior_volume(path: &HashSet<Range>) -> usize {
+    let max_y = path.iter().map(|r| r.max_y).max().unwrap();
+    let min_y = path.iter().map(|r| r.min_y).min().unwrap();
+    let mut count = 0;
+    let mut it = min_y..=max_y;
+    while let Some(y) = it.next() {
+        let mut current_x_index: isize = 0;
+        let mut inside: bool = false;
+        let (path_instances, number_of_identical_cols) = find_path_in_column_y(path, y);
+        for path_instance in path_instances {
+            if inside {
+                count += ((path_instance.0 - current_x_index) * number_of_identical_cols) as usize;
+            }
+            if path_instance.0 == path_instance.1
+                || (in_path(path, path_instance.0, y + 1) && in_path(path, path_instance.1, y - 1))
+                || (in_path(path, path_instance.0, y - 1) && in_path(path, path_instance.1, y + 1))
+            {
+                inside = !inside;
+            }
+            current_x_index = path_instance.1 + 1;
+        }
+        if number_of_identical_cols > 1 {
+            it.nth(number_of_identical_cols as usize - 2);
+        }
+    }
+    count
+}
+
+// The two parts read different instructions out of each line of the dig plan, but both boil down
+// to a direction (U, D, L or R) and a distance.
+pub struct DigPlan {
+    pub part1_instructions: Vec<(char, usize)>,
+    pub part2_instructions: Vec<(char, usize)>,
+}
+
+// Follow the instructions to dig out the trench, then work out how much lava the lagoon holds.
+fn dig(instructions: &[(char, usize)]) -> usize {
+    let mut path: HashSet<Range> = HashSet::new();
+    let mut path_len: usize = 0;
+    let mut current_location: (isize, isize) = (0, 0);
+    for (direction, distance) in instructions {
+        let new_location: (isize, isize) = match direction {
+            'U' => (current_location.0 - *distance as isize, current_location.1),
+            'D' => (current_location.0 + *distance as isize, current_location.1),
+            'R' => (current_location.0, current_location.1 + *distance as isize),
+            'L' => (current_location.0, current_location.1 - *distance as isize),
+            _ => panic!("Unknown direction"),
+        };
+        path.insert(Range {
+            min_x: std::cmp::min(current_location.0, new_location.0),
+            max_x: std::cmp::max(current_location.0, new_location.0),
+            min_y: std::cmp::min(current_location.1, new_location.1),
+            max_y: std::cmp::max(current_location.1, new_location.1),
+        });
+        path_len += distance;
+        current_location = new_location;
+    }
+    lagoon_interior_volume(&path) + path_len
+}
+
+// Why the instructions don't dig a trench we can measure, if they don't: they have to take us
+// back to where we started, without ever turning straight back on ourselves (the sweep in
+// `lagoon_interior_volume` gets lost on a trench with a dead end in it). Gives the index of the
+// instruction that's to blame along with what's wrong.
+fn check_trench(instructions: &[(char, usize)]) -> Result<(), (usize, &'static str)> {
+    let Some(last) = instructions.len().checked_sub(1) else {
+        return Err((0, "expected some instructions"));
+    };
+    let mut location: (isize, isize) = (0, 0);
+    for (i, &(direction, distance)) in instructions.iter().enumerate() {
+        let previous = instructions[(i + last) % instructions.len()].0;
+        let opposite = match direction {
+            'U' => 'D',
+            'D' => 'U',
+            'L' => 'R',
+            _ => 'L',
+        };
+        if previous == opposite {
+            return Err((i, "trench turns straight back on itself"));
+        }
+        let step = |at: isize, sign: isize| {
+            isize::try_from(distance)
+                .ok()
+                .and_then(|distance| at.checked_add(sign * distance))
+                .ok_or((i, "distance is too big"))
+        };
+        location = match direction {
+            'U' => (step(location.0, -1)?, location.1),
+            'D' => (step(location.0, 1)?, location.1),
+            'R' => (location.0, step(location.1, 1)?),
+            _ => (location.0, step(location.1, -1)?),
+        };
+    }
+    if location != (0, 0) {
+        return Err((last, "trench doesn't end where it started"));
+    }
+    Ok(())
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    const TITLE: &'static str = "Lavaduct Lagoon";
+    const NOTES: &'static str =
+        "Dig the trench as ranges, then sweep across counting inside cells, skipping identical columns.";
+
+    fn parse(&self, input: &str) -> Result<DigPlan, ParseError> {
+        let mut part1_instructions: Vec<(char, usize)> = vec![];
+        let mut part2_instructions: Vec<(char, usize)> = vec![];
+        let lines = error::lines(input).collect::<Vec<error::Line>>();
+        for &line in &lines {
+            let split = line.text.split_whitespace().collect::<Vec<&str>>();
+            if split.len() != 3 {
+                return Err(line.error(line.text, "expected a direction, distance and colour"));
+            }
+            let direction = match split[0] {
+                "U" => 'U',
+                "D" => 'D',
+                "L" => 'L',
+                "R" => 'R',
+                _ => return Err(line.error(split[0], "expected a direction (U, D, L or R)")),
+            };
+            let distance = line.parse::<usize>(split[1], "a distance")?;
+            part1_instructions.push((direction, distance));
+
+            let temp = split[2]
+                .strip_prefix("(#")
+                .and_then(|temp| temp.strip_suffix(')'))
+                .filter(|temp| temp.len() == 6 && temp.chars().all(|c| c.is_ascii_hexdigit()))
+                .ok_or_else(|| line.error(split[2], "expected a colour like (#70c710)"))?;
+            let direction = match &temp[5..] {
+                "3" => 'U',
+                "1" => 'D',
+                "0" => 'R',
+                "2" => 'L',
+                _ => return Err(line.error(&temp[5..], "expected a direction (0, 1, 2 or 3)")),
+            };
+            let distance = usize::from_str_radix(&temp[0..5], 16)
+                .map_err(|_| line.error(temp, "expected a hex distance"))?;
+            part2_instructions.push((direction, distance));
+        }
+        for instructions in [&part1_instructions, &part2_instructions] {
+            if let Err((i, message)) = check_trench(instructions) {
+                return Err(match lines.get(i) {
+                    Some(line) => line.error(line.text, message),
+                    None => ParseError::missing(1, message),
+                });
+            }
+        }
+        Ok(DigPlan {
+            part1_instructions,
+            part2_instructions,
+        })
+    }
+
+    fn part1(&self, dig_plan: &DigPlan) -> Result<Answer, SolveError> {
+        Ok(dig(&dig_plan.part1_instructions).into())
+    }
+
+    fn part2(&self, dig_plan: &DigPlan) -> Option<Result<Answer, SolveError>> {
+        Some(Ok(dig(&dig_plan.part2_instructions).into()))
+    }
+
+    // Size is roughly the number of instructions. Both parts dig the same random shape (the
+    // outline of a random tree, so it never crosses itself), stretched by a few metres a step for
+    // part 1 and thousands for part 2.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let cells = size.unwrap_or(700).max(4) / 2;
+        let side = 4 * (cells as f64).sqrt() as usize + 4;
+        let outline = generate::random_loop(rng, side, side, cells);
+        let corners = (0..outline.len())
+            .filter(|&i| {
+                let previous = outline[(i + outline.len() - 1) % outline.len()];
+                let next = outline[(i + 1) % outline.len()];
+                previous.0 != next.0 && previous.1 != next.1
+            })
+            .map(|i| outline[i])
+            .collect::<Vec<(usize, usize)>>();
+        // Where each row or column of the outline ends up once it's stretched.
+        let mut stretch = |most: usize| {
+            let mut at = 0;
+            (0..side)
+                .map(|_| {
+                    at += rng.usize(1..=most);
+                    at
+                })
+                .collect::<Vec<usize>>()
+        };
+        let (rows1, cols1) = (stretch(4), stretch(4));
+        let (rows2, cols2) = (stretch(0xfffff / side), stretch(0xfffff / side));
+        let lines = (0..corners.len()).map(|i| {
+            let (from, to) = (corners[i], corners[(i + 1) % corners.len()]);
+            let (direction, code) = match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+                (Ordering::Greater, _) => ('D', 1),
+                (Ordering::Less, _) => ('U', 3),
+                (_, Ordering::Greater) => ('R', 0),
+                _ => ('L', 2),
+            };
+            let distance = |rows: &[usize], cols: &[usize]| {
+                rows[from.0].abs_diff(rows[to.0]) + cols[from.1].abs_diff(cols[to.1])
+            };
+            format!(
+                "{} {} (#{:05x}{})",
+                direction,
+                distance(&rows1, &cols1),
+                distance(&rows2, &cols2),
+                code
+            )
+        });
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day18.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(Day18.part1(&input), Ok(Answer::Int(62)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(Day18.part2(&input), Some(Ok(Answer::Int(952408144115))));
+    }
+
+    #[test]
+    fn test_parse_unclosed_trench() {
+        assert!(Day18.parse("").is_err());
+        assert!(Day18.parse("R 6 (#000062)\n").is_err());
+        assert!(Day18.parse("R 6 (#000062)\nL 6 (#000060)\n").is_err());
+        assert!(Day18
+            .parse("R 1 (#000020)\nD 1 (#000021)\nL 1 (#000022)\n")
+            .is_err());
+    }
+
+    // Lines like `R 6 (#70c710)`, going round a rectangle (a different one for each part) so
+    // that the trench closes.
+    fn input() -> impl Strategy<Value = String> {
+        let side = || 1..0x100000usize;
+        (1..10usize, 1..10usize, side(), side()).prop_map(|(width, height, width2, height2)| {
+            let lines = [
+                ('R', width, width2, 0),
+                ('D', height, height2, 1),
+                ('L', width, width2, 2),
+                ('U', height, height2, 3),
+            ]
+            .map(|(direction, distance, hex_distance, hex_direction)| {
+                format!(
+                    "{} {} (#{:05x}{})",
+                    direction, distance, hex_distance, hex_direction
+                )
+            });
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day18, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day18.parse(&input);
+        }
+    }
+}
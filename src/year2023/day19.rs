@@ -0,0 +1,469 @@
+use std::collections::{HashMap, VecDeque};
+
+use fastrand::Rng;
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::error::{self, Line, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, Deserialize)]
+pub struct Part {
+    pub x: usize,
+    pub m: usize,
+    pub a: usize,
+    pub s: usize,
+}
+
+impl Part {
+    fn sum_of_ratings(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Clone)]
+pub enum Instruction {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+pub struct Criteria {
+    pub value: usize,
+    pub less_than: bool,
+    pub part_component: char,
+}
+
+pub struct Workflow {
+    pub rules: Vec<(Criteria, Instruction)>,
+    pub fallback: Instruction,
+}
+
+fn execute_workflow(part: &Part, workflow: &Workflow) -> Instruction {
+    for rule in &workflow.rules {
+        let criteria = &rule.0;
+        let instruction = &rule.1;
+        let part_component = criteria.part_component;
+        let value = match part_component {
+            'x' => part.x,
+            'm' => part.m,
+            'a' => part.a,
+            's' => part.s,
+            _ => panic!("Invalid part component"),
+        };
+        if (criteria.less_than && value < criteria.value)
+            || (!criteria.less_than && value > criteria.value)
+        {
+            return instruction.clone();
+        }
+    }
+    workflow.fallback.clone()
+}
+
+#[derive(Clone)]
+struct Range {
+    min_max_vals: HashMap<char, (usize, usize)>,
+}
+
+impl Range {
+    fn count_parts_in_range(self) -> usize {
+        self.min_max_vals
+            .iter()
+            .map(|(_, (min, max))| (max + 1).saturating_sub(*min))
+            .product()
+    }
+
+    fn count_acceptable_parts_in_range(
+        &mut self,
+        entrypoint: String,
+        workflows: &HashMap<String, Workflow>,
+    ) -> usize {
+        let mut count: usize = 0;
+        let workflow = workflows.get(&entrypoint).expect("Could not find workflow");
+        for rule in &workflow.rules {
+            let mut range_that_passes_rule = self.clone();
+            let criteria = &rule.0;
+            let part_component = criteria.part_component;
+            // Narrow the ranges down rather than setting them, in case an earlier rule already
+            // cut off more than this one does.
+            let passing = range_that_passes_rule
+                .min_max_vals
+                .get_mut(&part_component)
+                .unwrap();
+            let failing = self.min_max_vals.get_mut(&part_component).unwrap();
+            if criteria.less_than {
+                passing.1 = passing.1.min(criteria.value.saturating_sub(1));
+                failing.0 = failing.0.max(criteria.value);
+            } else {
+                passing.0 = passing.0.max(criteria.value + 1);
+                failing.1 = failing.1.min(criteria.value);
+            }
+
+            match &rule.1 {
+                Instruction::Accept => {
+                    count += range_that_passes_rule.count_parts_in_range();
+                }
+                Instruction::Reject => {}
+                Instruction::Workflow(w) => {
+                    count += range_that_passes_rule
+                        .count_acceptable_parts_in_range(w.to_string(), workflows);
+                }
+            }
+        }
+        match &workflow.fallback {
+            Instruction::Accept => {
+                count += self.clone().count_parts_in_range();
+            }
+            Instruction::Reject => {}
+            Instruction::Workflow(w) => {
+                count += self.count_acceptable_parts_in_range(w.to_string(), workflows);
+            }
+        }
+        count
+    }
+}
+
+// Part 1 the part 2 way, to check the two against each other: a part is accepted if the range
+// of just its ratings has an acceptable part in it.
+pub fn accepted_ratings_by_ranges(system: &System) -> usize {
+    system
+        .parts
+        .iter()
+        .filter(|part| {
+            let mut range = Range {
+                min_max_vals: HashMap::from([
+                    ('x', (part.x, part.x)),
+                    ('m', (part.m, part.m)),
+                    ('a', (part.a, part.a)),
+                    ('s', (part.s, part.s)),
+                ]),
+            };
+            range.count_acceptable_parts_in_range("in".to_string(), &system.workflows) > 0
+        })
+        .map(Part::sum_of_ratings)
+        .sum()
+}
+
+fn parse_instruction(instruction_str: &str) -> Instruction {
+    match instruction_str {
+        "A" => Instruction::Accept,
+        "R" => Instruction::Reject,
+        x => Instruction::Workflow(x.to_owned()),
+    }
+}
+
+pub struct System {
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<Part>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    const TITLE: &'static str = "Aplenty";
+    const NOTES: &'static str =
+        "Part 1 sends each part through the workflows. Part 2 splits ranges of ratings down the workflows instead.";
+
+    fn parse(&self, input: &str) -> Result<System, ParseError> {
+        let mut lines = error::lines(input);
+
+        // Absolutely grim parsing code for the workflows, but whatever.
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        // Everywhere that gets sent to, so we can check the workflows all exist once we're done.
+        let mut destinations: Vec<(Line, &str)> = vec![];
+        for line in lines.by_ref() {
+            if line.text.is_empty() {
+                break;
+            }
+            let (name, rules_str) = line.split_once(line.text, "{")?;
+            let rules_str = rules_str
+                .strip_suffix('}')
+                .ok_or_else(|| line.error(rules_str, "expected the rules to end with }"))?;
+            let mut split2 = rules_str.split(',').collect::<Vec<&str>>();
+            // `split` always gives at least one item.
+            let fallback_str = split2.pop().unwrap_or_default();
+            destinations.push((line, fallback_str));
+            let fallback = parse_instruction(fallback_str);
+            let mut rules: Vec<(Criteria, Instruction)> = vec![];
+            for rule in split2 {
+                let (condition, instruction_str) = line.split_once(rule, ":")?;
+                destinations.push((line, instruction_str));
+                let instruction = parse_instruction(instruction_str);
+                let less_than = condition.contains('<');
+                let (part_component, value) =
+                    line.split_once(condition, if less_than { "<" } else { ">" })?;
+                if !["x", "m", "a", "s"].contains(&part_component) {
+                    return Err(line.error(part_component, "expected x, m, a or s"));
+                }
+                let criteria = Criteria {
+                    value: line.parse(value, "a rating")?,
+                    less_than,
+                    part_component: part_component.chars().next().unwrap_or_default(),
+                };
+                rules.push((criteria, instruction));
+            }
+
+            workflows.insert(name.to_string(), Workflow { rules, fallback });
+        }
+        if !workflows.contains_key("in") {
+            return Err(ParseError::missing(1, "expected an \"in\" workflow"));
+        }
+        if let Some((line, name)) = destinations
+            .into_iter()
+            .find(|(_, name)| !["A", "R"].contains(name) && !workflows.contains_key(*name))
+        {
+            return Err(line.error(name, "expected the name of a workflow"));
+        }
+
+        // Parse the parts.
+        let parts: Vec<Part> = lines
+            .map(|line| {
+                serde_json::from_str(
+                    &line
+                        .text
+                        .replace("x=", "\"x\":")
+                        .replace("m=", "\"m\":")
+                        .replace("a=", "\"a\":")
+                        .replace("s=", "\"s\":"),
+                )
+                .map_err(|_| line.error(line.text, "expected a part like {x=1,m=2,a=3,s=4}"))
+            })
+            .collect::<Result<Vec<Part>, ParseError>>()?;
+
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(&self, system: &System) -> Result<Answer, SolveError> {
+        let mut sum: usize = 0;
+        for part in &system.parts {
+            let mut workflow = "in".to_string();
+            loop {
+                let result = execute_workflow(
+                    part,
+                    system
+                        .workflows
+                        .get(&workflow)
+                        .expect("Could not find workflow"),
+                );
+                match result {
+                    Instruction::Accept => {
+                        sum += part.sum_of_ratings();
+                        break;
+                    }
+                    Instruction::Reject => {
+                        break;
+                    }
+                    Instruction::Workflow(w) => workflow = w,
+                }
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, system: &System) -> Option<Result<Answer, SolveError>> {
+        let mut range = Range {
+            min_max_vals: HashMap::from([
+                ('x', (1, 4000)),
+                ('m', (1, 4000)),
+                ('a', (1, 4000)),
+                ('s', (1, 4000)),
+            ]),
+        };
+        Some(Ok(range
+            .count_acceptable_parts_in_range("in".to_string(), &system.workflows)
+            .into()))
+    }
+
+    // Size is the number of workflows. Like the real input, they form a tree from `in`, each
+    // sent to from just one place, and each rule splits the ratings that can get that far
+    // somewhere in the middle, so every rule can pass or fail.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(550).max(1);
+        let mut names = generate::names(rng, size - 1, 2, "abcdefghijklmnopqrstuvwxyz", &["in"]);
+        let mut queue = VecDeque::from([("in".to_string(), [(1, 4000); 4])]);
+        let mut workflows = vec![];
+        while let Some((name, mut ranges)) = queue.pop_front() {
+            // Some rules and then a fallback, which is a rule with no condition.
+            let count = rng.usize(1..=4);
+            let mut rules = vec![];
+            for rule in 0..=count {
+                let component = rng.usize(..4);
+                let letter = ['x', 'm', 'a', 's'][component];
+                let (min, max) = ranges[component];
+                let (condition, passing) = if rule == count || min == max {
+                    (String::new(), ranges)
+                } else if rng.bool() {
+                    let value = rng.usize(min + 1..=max);
+                    let mut passing = ranges;
+                    passing[component] = (min, value - 1);
+                    ranges[component] = (value, max);
+                    (format!("{}<{}:", letter, value), passing)
+                } else {
+                    let value = rng.usize(min..max);
+                    let mut passing = ranges;
+                    passing[component] = (value + 1, max);
+                    ranges[component] = (min, value);
+                    (format!("{}>{}:", letter, value), passing)
+                };
+                let destination = if !names.is_empty() && (queue.is_empty() || rng.u8(..3) > 0) {
+                    let next = names.pop().unwrap();
+                    queue.push_back((next.clone(), passing));
+                    next
+                } else if rng.bool() {
+                    "A".to_string()
+                } else {
+                    "R".to_string()
+                };
+                let last = condition.is_empty();
+                rules.push(condition + &destination);
+                if last {
+                    break;
+                }
+            }
+            workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+        }
+        rng.shuffle(&mut workflows);
+        let parts = (0..200).map(|_| {
+            format!(
+                "{{x={},m={},a={},s={}}}",
+                rng.u32(1..=4000),
+                rng.u32(1..=4000),
+                rng.u32(1..=4000),
+                rng.u32(1..=4000)
+            )
+        });
+        let mut lines = workflows;
+        lines.push(String::new());
+        lines.extend(parts);
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day19.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(Day19.part1(&input), Ok(Answer::Int(19114)));
+    }
+
+    #[test]
+    fn test_accepted_ratings_by_ranges() {
+        let input = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(accepted_ratings_by_ranges(&input), 19114);
+    }
+
+    // Cut down by the differential harness: a ">" rule doesn't pass a rating equal to its value.
+    #[test]
+    fn test_part1_greater_than_is_strict() {
+        let input = Day19
+            .parse(
+                "in{s>2136:ds,m>3891:A,a<3962:R,x>611:A,A}\n\
+                 ds{x>777:A,A}\n\
+                 \n\
+                 {x=1306,m=3891,a=1003,s=799}\n",
+            )
+            .unwrap();
+        assert_eq!(Day19.part1(&input), Ok(Answer::Int(0)));
+        assert_eq!(accepted_ratings_by_ranges(&input), 0);
+    }
+
+    // Cut down by the differential harness: once the rule rejects every s below 3340, what's
+    // left of the range of just this part's ratings is empty, not s from 3340 to 2899.
+    #[test]
+    fn test_accepted_ratings_by_ranges_empty_range() {
+        let input = Day19
+            .parse("in{s<3340:R,A}\n\n{x=3287,m=367,a=3042,s=2899}\n")
+            .unwrap();
+        assert_eq!(accepted_ratings_by_ranges(&input), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(Day19.part2(&input), Some(Ok(Answer::Int(167409079868000))));
+    }
+
+    // Everything that gets past `x<100` has an x of at least 100, so `x>50` can't let any x
+    // below that back in.
+    #[test]
+    fn test_part2_rules_narrow_ranges() {
+        let input = Day19
+            .parse("in{x<100:R,x>50:A,R}\n\n{x=1,m=1,a=1,s=1}\n")
+            .unwrap();
+        assert_eq!(
+            Day19.part2(&input),
+            Some(Ok(Answer::Int(3901 * 4000 * 4000 * 4000)))
+        );
+    }
+
+    // Workflows like `px{a<2006:qkq,m>2090:A,rfg}`, a blank line, then parts like
+    // `{x=787,m=2655,a=1222,s=2876}`. The first workflow is `in`, and rules only send parts to
+    // workflows that exist.
+    fn input() -> impl Strategy<Value = String> {
+        let rule = (
+            prop::sample::select(vec!['x', 'm', 'a', 's']),
+            prop::sample::select(vec!['<', '>']),
+            1..4000usize,
+            any::<prop::sample::Index>(),
+        );
+        let workflow = (
+            prop::collection::vec(rule, 0..4),
+            any::<prop::sample::Index>(),
+        );
+        let part = prop::collection::vec(0..4000usize, 4);
+        (
+            prop::collection::vec(workflow, 1..5),
+            prop::collection::vec(part, 0..5),
+        )
+            .prop_map(|(workflows, parts)| {
+                let mut names = (0..workflows.len())
+                    .map(|i| match i {
+                        0 => "in".to_string(),
+                        _ => format!("w{}", i),
+                    })
+                    .collect::<Vec<String>>();
+                names.extend(["A".to_string(), "R".to_string()]);
+                let mut lines = vec![];
+                for (name, (rules, fallback)) in names.iter().zip(&workflows) {
+                    let mut rules = rules
+                        .iter()
+                        .map(|(category, comparison, value, destination)| {
+                            let destination = destination.get(&names);
+                            format!("{}{}{}:{}", category, comparison, value, destination)
+                        })
+                        .collect::<Vec<String>>();
+                    rules.push(fallback.get(&names).clone());
+                    lines.push(format!("{}{{{}}}", name, rules.join(",")));
+                }
+                lines.push(String::new());
+                lines.extend(
+                    parts
+                        .iter()
+                        .map(|p| format!("{{x={},m={},a={},s={}}}", p[0], p[1], p[2], p[3])),
+                );
+                generate::join_lines(&lines)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day19, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day19.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,448 @@
+use fastrand::Rng;
+use num_integer::lcm;
+use std::collections::{HashMap, HashSet};
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+trait Module {
+    fn insert_input_module(&mut self, _input_module: &str) {
+        panic!("Can't insert input module for this module type")
+    }
+    fn receive_pulse(&mut self, input_module: String, input_pulse: Pulse) -> Vec<(Pulse, String)>;
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Default)]
+struct Conjunction {
+    last_pulses_received: HashMap<String, Pulse>,
+    destination_modules: Vec<String>,
+}
+
+impl Conjunction {
+    fn all_high(&self) -> bool {
+        self.last_pulses_received
+            .values()
+            .all(|p| matches!(p, Pulse::High))
+    }
+}
+
+impl Module for Conjunction {
+    fn insert_input_module(&mut self, input_module: &str) {
+        self.last_pulses_received
+            .insert(input_module.to_owned(), Pulse::Low);
+    }
+
+    fn receive_pulse(&mut self, input_module: String, input_pulse: Pulse) -> Vec<(Pulse, String)> {
+        self.last_pulses_received.insert(input_module, input_pulse);
+        let output_pulse = if self.all_high() {
+            Pulse::Low
+        } else {
+            Pulse::High
+        };
+        self.destination_modules
+            .clone()
+            .into_iter()
+            .map(|m: String| (output_pulse, m))
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct FlipFlop {
+    on: bool,
+    destination_modules: Vec<String>,
+}
+
+impl Module for FlipFlop {
+    fn receive_pulse(&mut self, _input_module: String, input_pulse: Pulse) -> Vec<(Pulse, String)> {
+        if matches!(input_pulse, Pulse::Low) {
+            let output_pulse = if self.on { Pulse::Low } else { Pulse::High };
+            self.on = !self.on;
+            return self
+                .destination_modules
+                .clone()
+                .into_iter()
+                .map(|m: String| (output_pulse, m))
+                .collect();
+        }
+        vec![]
+    }
+}
+
+#[derive(Default)]
+struct Broadcaster {
+    destination_modules: Vec<String>,
+}
+
+impl Module for Broadcaster {
+    fn receive_pulse(&mut self, _input_module: String, input_pulse: Pulse) -> Vec<(Pulse, String)> {
+        self.destination_modules
+            .clone()
+            .into_iter()
+            .map(|m| (input_pulse, m))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum ModuleType {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+// A line of the input, before we've built up any state.
+pub struct ModuleConfiguration {
+    pub name: String,
+    pub module_type: ModuleType,
+    pub destination_modules: Vec<String>,
+}
+
+// Build a fresh set of modules from the configuration, with every flip-flop off and every
+// conjunction remembering a low pulse from each of its inputs.
+fn build_modules(configuration: &[ModuleConfiguration]) -> HashMap<String, Box<dyn Module>> {
+    let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
+    let mut rev_module_mapping: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut conjunction_modules: Vec<String> = vec![];
+    for module in configuration {
+        let destination_modules = module.destination_modules.clone();
+        match module.module_type {
+            ModuleType::Broadcaster => {
+                modules.insert(
+                    module.name.clone(),
+                    Box::new(Broadcaster {
+                        destination_modules,
+                    }),
+                );
+            }
+            ModuleType::FlipFlop => {
+                modules.insert(
+                    module.name.clone(),
+                    Box::new(FlipFlop {
+                        destination_modules,
+                        ..Default::default()
+                    }),
+                );
+            }
+            ModuleType::Conjunction => {
+                conjunction_modules.push(module.name.clone());
+                modules.insert(
+                    module.name.clone(),
+                    Box::new(Conjunction {
+                        destination_modules,
+                        ..Default::default()
+                    }),
+                );
+            }
+        }
+
+        for destination_module in &module.destination_modules {
+            rev_module_mapping
+                .entry(destination_module.clone())
+                .or_default()
+                .insert(module.name.clone());
+        }
+    }
+
+    // A conjunction that nothing sends to has no inputs to remember.
+    for conjunction_module_name in &conjunction_modules {
+        for module_name in rev_module_mapping
+            .get(conjunction_module_name)
+            .into_iter()
+            .flatten()
+        {
+            modules
+                .get_mut(conjunction_module_name)
+                .unwrap()
+                .insert_input_module(module_name);
+        }
+    }
+    modules
+}
+
+// Press the button once and let the pulses propagate until everything settles down.
+//
+// Returns every pulse sent (including the one from the button) as (pulse, destination, source).
+fn press_button(modules: &mut HashMap<String, Box<dyn Module>>) -> Vec<(Pulse, String, String)> {
+    let mut active_pulses: Vec<(Pulse, String, String)> =
+        vec![(Pulse::Low, "broadcaster".into(), "human".into())];
+    let mut all_pulses = active_pulses.clone();
+    loop {
+        let mut next_pulses: Vec<(Pulse, String, String)> = vec![];
+        for pulse in active_pulses {
+            if let Some(next_module) = modules.get_mut(&pulse.1) {
+                next_pulses.extend(
+                    next_module
+                        .receive_pulse(pulse.2, pulse.0)
+                        .iter()
+                        .map(|p| (p.0, p.1.clone(), pulse.1.clone())),
+                )
+            }
+        }
+        if next_pulses.is_empty() {
+            return all_pulses;
+        }
+        all_pulses.extend(next_pulses.iter().cloned());
+        active_pulses = next_pulses;
+    }
+}
+
+// Part 2 gives up after this many presses. Our input's cycles are a few thousand presses long.
+const MAX_PRESSES: usize = 1 << 20;
+
+// Whether each of the named modules is a conjunction that the button can reach, sending to the
+// conjunction that sends to rx, which is how part 2 expects the input to be wired up.
+fn feeds_rx_conjunction<'a>(
+    configuration: &[ModuleConfiguration],
+    names: impl IntoIterator<Item = &'a String>,
+) -> bool {
+    let find = |name: &str| configuration.iter().find(|module| module.name == name);
+    let Some(rx_conjunction) = configuration.iter().find(|module| {
+        matches!(module.module_type, ModuleType::Conjunction)
+            && module.destination_modules.iter().any(|m| m == "rx")
+    }) else {
+        return false;
+    };
+    let mut reachable = HashSet::from(["broadcaster"]);
+    let mut queue = vec!["broadcaster"];
+    while let Some(name) = queue.pop() {
+        for destination in find(name).into_iter().flat_map(|m| &m.destination_modules) {
+            if reachable.insert(destination) {
+                queue.push(destination);
+            }
+        }
+    }
+    names.into_iter().all(|name| {
+        find(name).is_some_and(|module| {
+            matches!(module.module_type, ModuleType::Conjunction)
+                && module.destination_modules.contains(&rx_conjunction.name)
+                && reachable.contains(name.as_str())
+        })
+    })
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<ModuleConfiguration>;
+
+    const TITLE: &'static str = "Pulse Propagation";
+    const NOTES: &'static str =
+        "Simulate the pulses. Part 2 takes the LCM of the presses for each (hardcoded) input to rx's conjunction.";
+
+    fn parse(&self, input: &str) -> Result<Vec<ModuleConfiguration>, ParseError> {
+        let mut configuration: Vec<ModuleConfiguration> = vec![];
+        for line in error::lines(input) {
+            let (module, destinations) = line.split_once(line.text, " -> ")?;
+            let destination_modules = destinations
+                .split(", ")
+                .map(|m| m.into())
+                .collect::<Vec<String>>();
+            let (name, module_type) = if module == "broadcaster" {
+                ("broadcaster".to_string(), ModuleType::Broadcaster)
+            } else if let Some(name) = module.strip_prefix('%') {
+                (name.to_string(), ModuleType::FlipFlop)
+            } else if let Some(name) = module.strip_prefix('&') {
+                (name.to_string(), ModuleType::Conjunction)
+            } else {
+                return Err(line.error(module, "expected broadcaster, %name or &name"));
+            };
+            configuration.push(ModuleConfiguration {
+                name,
+                module_type,
+                destination_modules,
+            });
+        }
+        Ok(configuration)
+    }
+
+    fn part1(&self, configuration: &Vec<ModuleConfiguration>) -> Result<Answer, SolveError> {
+        let mut modules = build_modules(configuration);
+        let mut low_pulse_count: usize = 0;
+        let mut high_pulse_count: usize = 0;
+        for press in 1..=1000 {
+            let (mut low, mut high) = (0, 0);
+            for pulse in press_button(&mut modules) {
+                match pulse.0 {
+                    Pulse::Low => low += 1,
+                    Pulse::High => high += 1,
+                }
+            }
+            log::debug!(press = press, low = low, high = high; "pulses sent");
+            low_pulse_count += low;
+            high_pulse_count += high;
+        }
+        Ok((low_pulse_count * high_pulse_count).into())
+    }
+
+    fn part2(
+        &self,
+        configuration: &Vec<ModuleConfiguration>,
+    ) -> Option<Result<Answer, SolveError>> {
+        let mut modules = build_modules(configuration);
+        let mut button_press_count: usize = 0;
+
+        // By inspection, rx is triggered by a conjunction module called kh, which in turn is
+        // triggered by four conjunction modules called pv, qh, xm and hz. So we need all of those
+        // modules to send a high pulse at the same time.
+        //
+        // Soooooooooooo, what I'm going to do is...
+        //  - Hardcode those module names
+        //  - Find out how many button presses it takes each of them to send a high pulse
+        //  - Assume that this stuff all works in cycles (without doing any thinking whatsoever
+        //    about whether that's likely to be true).
+        //  - Lowest common multiple, bish bash bosh
+        //
+        // Other people's inputs call them something else, so check they're there before we start
+        // pressing, or we'd press forever.
+        let mut graemes_hardcoded_magic_map: HashMap<String, usize> = HashMap::from([
+            ("pv".into(), 0),
+            ("qh".into(), 0),
+            ("xm".into(), 0),
+            ("hz".into(), 0),
+        ]);
+        if !feeds_rx_conjunction(configuration, graemes_hardcoded_magic_map.keys()) {
+            return Some(Err(SolveError::new(
+                "pv, qh, xm and hz don't all feed the conjunction that sends to rx",
+            )));
+        }
+        while graemes_hardcoded_magic_map.values().any(|v| v == &0) {
+            // Even when they are there, nothing says they ever send a high pulse.
+            if button_press_count == MAX_PRESSES {
+                return Some(Err(SolveError::new(format!(
+                    "pv, qh, xm and hz didn't all send a high pulse in {} presses",
+                    MAX_PRESSES
+                ))));
+            }
+            button_press_count += 1;
+            let pulses = press_button(&mut modules);
+            log::trace!(press = button_press_count, pulses = pulses.len(); "pulses sent");
+            for (k, v) in graemes_hardcoded_magic_map.iter_mut() {
+                if pulses
+                    .iter()
+                    .any(|p| matches!(p.0, Pulse::High) && p.2 == *k && v == &0)
+                {
+                    *v = button_press_count;
+                    log::debug!(module = k.as_str(), press = button_press_count; "first high pulse");
+                }
+            }
+        }
+        Some(Ok(graemes_hardcoded_magic_map
+            .values()
+            .fold(1, |a, b| lcm(a, *b))
+            .into()))
+    }
+
+    // Size is the number of flip-flops in each of four counters. As in the real input, each
+    // counter counts button presses up to a prime that needs all of its bits and then resets
+    // itself, sending a high pulse from one of the conjunctions part 2 watches on the way. There
+    // are at most 20 bits, so the counts come round before part 2 gives up.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        const WATCHED: [&str; 4] = ["pv", "qh", "xm", "hz"];
+        let bits = size.unwrap_or(12).clamp(3, 20);
+        let taken = ["pv", "qh", "xm", "hz", "kh", "rx"];
+        let names = generate::names(rng, 4 * (bits + 1), 2, "abcdefghijklmnopqrstuvwxyz", &taken);
+        let mut lines = vec![];
+        let mut firsts = vec![];
+        for (counter, watched) in names.chunks(bits + 1).zip(WATCHED) {
+            let (hub, flip_flops) = counter.split_last().unwrap();
+            let (low, high) = (1 << (bits - 1), 1 << bits);
+            let mut period = generate::next_prime(rng.u64(low..high));
+            if period >= high {
+                period = generate::next_prime(low);
+            }
+            // Each flip-flop is a bit of the count. The hub hears from the bits that are set in
+            // the period, and when they all are, adds on whatever takes the count round to 0.
+            let mut resets = vec![watched.to_string(), flip_flops[0].clone()];
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut destinations = flip_flops
+                    .get(bit + 1)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<String>>();
+                match period & (1 << bit) {
+                    0 => resets.push(flip_flop.clone()),
+                    _ => destinations.push(hub.clone()),
+                }
+                lines.push(format!("%{} -> {}", flip_flop, destinations.join(", ")));
+            }
+            lines.push(format!("&{} -> {}", hub, resets.join(", ")));
+            lines.push(format!("&{} -> kh", watched));
+            firsts.push(flip_flops[0].clone());
+        }
+        lines.push("&kh -> rx".to_string());
+        lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+        rng.shuffle(&mut lines);
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day20.txt");
+    const SECOND_EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day20_2.txt");
+
+    // There's no example for part 2, since it relies on the rx module that only real inputs have.
+    #[test]
+    fn test_part1() {
+        let input = Day20.parse(EXAMPLE).unwrap();
+        assert_eq!(Day20.part1(&input), Ok(Answer::Int(32000000)));
+        let input = Day20.parse(SECOND_EXAMPLE).unwrap();
+        assert_eq!(Day20.part1(&input), Ok(Answer::Int(11687500)));
+    }
+
+    // Nothing sends to b, which used to panic when setting up its inputs. Nothing sends a high
+    // pulse either, so that's 2000 low pulses times none.
+    #[test]
+    fn test_conjunction_without_inputs() {
+        let input = Day20.parse("broadcaster -> a\n&b -> a\n").unwrap();
+        assert_eq!(Day20.part1(&input), Ok(Answer::Int(0)));
+    }
+
+    // Without the modules part 2 watches, it used to press the button forever.
+    #[test]
+    fn test_part2_without_rx() {
+        let input = Day20.parse(SECOND_EXAMPLE).unwrap();
+        assert!(matches!(Day20.part2(&input), Some(Err(_))));
+        let input = Day20.parse("").unwrap();
+        assert!(matches!(Day20.part2(&input), Some(Err(_))));
+    }
+
+    // A broadcaster and lines like `%a -> inv, con`.
+    fn input() -> impl Strategy<Value = String> {
+        let name = || grammar::chars("abcdefgh", 1..3);
+        let module = (prop::sample::select(vec!["%", "&"]), name());
+        let modules = prop::collection::vec((module, prop::collection::vec(name(), 1..4)), 0..8);
+        (prop::collection::vec(name(), 1..4), modules).prop_map(|(targets, modules)| {
+            let mut lines = vec![format!("broadcaster -> {}", targets.join(", "))];
+            lines.extend(modules.iter().map(|((kind, name), destinations)| {
+                format!("{}{} -> {}", kind, name, destinations.join(", "))
+            }));
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day20, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day20.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+const NUMBER_OF_STEPS: usize = 64;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Point {
+    Rock,
+    Garden,
+}
+
+pub struct Garden {
+    pub grid: Vec<Vec<Point>>,
+    pub start: ((usize, usize), (isize, isize)),
+}
+
+fn count_attainable_plots(garden: &Garden, number_of_steps: usize) -> usize {
+    let grid = &garden.grid;
+    let max_row = grid.len() - 1;
+    let max_col = grid[0].len() - 1;
+
+    let mut attainable_plots: HashSet<((usize, usize), (isize, isize))> = HashSet::new();
+    attainable_plots.insert(garden.start);
+    for _ in 1..=number_of_steps {
+        let mut next_attainable_plots: HashSet<((usize, usize), (isize, isize))> = HashSet::new();
+        for (grid_coords, plot_coords) in attainable_plots {
+            let (grid_row, grid_col) = grid_coords;
+            let (plot_row, plot_col) = plot_coords;
+            if grid_row > 0 {
+                if grid[grid_row - 1][grid_col] == Point::Garden {
+                    next_attainable_plots.insert(((grid_row - 1, grid_col), plot_coords));
+                }
+            } else if grid[max_row][grid_col] == Point::Garden {
+                next_attainable_plots.insert(((max_row, grid_col), (plot_row - 1, plot_col)));
+            }
+            if grid_row < max_row {
+                if grid[grid_row + 1][grid_col] == Point::Garden {
+                    next_attainable_plots.insert(((grid_row + 1, grid_col), plot_coords));
+                }
+            } else if grid[0][grid_col] == Point::Garden {
+                next_attainable_plots.insert(((0, grid_col), (plot_row + 1, plot_col)));
+            }
+            if grid_col > 0 {
+                if grid[grid_row][grid_col - 1] == Point::Garden {
+                    next_attainable_plots.insert(((grid_row, grid_col - 1), plot_coords));
+                }
+            } else if grid[grid_row][max_col] == Point::Garden {
+                next_attainable_plots.insert(((grid_row, max_col), (plot_row, plot_col - 1)));
+            }
+            if grid_col < max_col {
+                if grid[grid_row][grid_col + 1] == Point::Garden {
+                    next_attainable_plots.insert(((grid_row, grid_col + 1), plot_coords));
+                }
+            } else if grid[grid_row][0] == Point::Garden {
+                next_attainable_plots.insert(((grid_row, 0), (plot_row, plot_col + 1)));
+            }
+        }
+        attainable_plots = next_attainable_plots;
+    }
+
+    attainable_plots.len()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    const TITLE: &'static str = "Step Counter";
+    const NOTES: &'static str = "Keep the set of plots reachable after each step. No part 2.";
+
+    fn parse(&self, input: &str) -> Result<Garden, ParseError> {
+        let mut grid: Vec<Vec<Point>> = vec![];
+        let mut start: Option<((usize, usize), (isize, isize))> = None;
+        for (row_index, line) in error::grid(input)?.iter().enumerate() {
+            let mut row: Vec<Point> = vec![];
+            for (col_index, c) in line.text.chars().enumerate() {
+                match c {
+                    '#' => row.push(Point::Rock),
+                    '.' => {
+                        row.push(Point::Garden);
+                    }
+                    'S' if start.is_none() => {
+                        row.push(Point::Garden);
+                        start = Some(((row_index, col_index), (0, 0)));
+                    }
+                    'S' => return Err(line.error_at(col_index, "expected only one start point")),
+                    _ => return Err(line.error_at(col_index, "expected #, . or S")),
+                }
+            }
+            grid.push(row);
+        }
+        Ok(Garden {
+            grid,
+            start: start.ok_or_else(|| ParseError::missing(1, "expected a start point"))?,
+        })
+    }
+
+    fn part1(&self, garden: &Garden) -> Result<Answer, SolveError> {
+        Ok(count_attainable_plots(garden, NUMBER_OF_STEPS).into())
+    }
+
+    // I do not understand part 2 at all.
+
+    // Size is the width (and height) of the map, which is always odd so that S can sit in the
+    // middle. As in the real input, S's row and column and the edges are clear of rocks.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(131) | 1;
+        let (middle, edge) = (size / 2, size - 1);
+        let rows = (0..size).map(|row| {
+            (0..size)
+                .map(|col| {
+                    if row == middle && col == middle {
+                        'S'
+                    } else if [row, col]
+                        .iter()
+                        .any(|&x| x == 0 || x == middle || x == edge)
+                        || rng.u8(..8) != 0
+                    {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect::<String>()
+        });
+        Some(generate::join_lines(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day21.txt");
+
+    // The example only takes 6 steps rather than the real 64.
+    #[test]
+    fn test_part1() {
+        let input = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(count_attainable_plots(&input, 6), 16);
+    }
+
+    // A grid of rocks and garden plots with one start point.
+    fn input() -> impl Strategy<Value = String> {
+        grammar::grid_with_one("#.", 'S', 1..10, 1..10).prop_map(|rows| generate::join_lines(&rows))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day21, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day21.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub struct Cube {
+    pub x: isize,
+    pub y: isize,
+    pub z: usize,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Brick {
+    pub cubes: HashSet<Cube>,
+    pub min_z: usize,
+}
+
+// Real bricks are a handful of cubes. Anything much bigger than this is a mistake, and would
+// take forever (and all the memory there is) to fill in cube by cube.
+const MAX_BRICK_CUBES: usize = 10_000;
+
+fn fall_from_i(bricks: &mut [Brick], i: usize) {
+    let mut occupied_cells: HashSet<Cube> = HashSet::new();
+    for (index, brick) in bricks.iter_mut().enumerate() {
+        if index < i {
+            occupied_cells.extend(brick.cubes.clone());
+            continue;
+        }
+
+        let mut new_min_z: usize = brick.min_z;
+        for z in (1..brick.min_z).rev() {
+            let new_set_of_cubes = brick
+                .cubes
+                .iter()
+                .map(|cube| Cube {
+                    x: cube.x,
+                    y: cube.y,
+                    z: cube.z - (brick.min_z - z),
+                })
+                .collect::<HashSet<Cube>>();
+            if occupied_cells.is_disjoint(&new_set_of_cubes) {
+                new_min_z = z;
+            } else {
+                break;
+            }
+        }
+        let new_set_of_cubes = brick
+            .cubes
+            .iter()
+            .map(|cube| Cube {
+                x: cube.x,
+                y: cube.y,
+                z: cube.z - (brick.min_z - new_min_z),
+            })
+            .collect::<HashSet<Cube>>();
+        occupied_cells.extend(new_set_of_cubes.clone());
+        brick.min_z = new_min_z;
+        brick.cubes = new_set_of_cubes;
+    }
+}
+
+// Remove brick `i` from a settled stack and count how many of the remaining bricks fall.
+fn count_falling_bricks(bricks: &[Brick], i: usize) -> usize {
+    let mut bricks_without_i = bricks.to_vec();
+    bricks_without_i.remove(i);
+    let mut fallen_bricks_without_i = bricks_without_i.clone();
+    fall_from_i(&mut fallen_bricks_without_i, i);
+    (i..bricks_without_i.len())
+        .filter(|j| fallen_bricks_without_i[*j] != bricks_without_i[*j])
+        .count()
+}
+
+fn settle(bricks: &[Brick]) -> Vec<Brick> {
+    let mut settled_bricks = bricks.to_vec();
+    fall_from_i(&mut settled_bricks, 0);
+    settled_bricks
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    const TITLE: &'static str = "Sand Slabs";
+    const NOTES: &'static str =
+        "Settle the bricks, then take each out in turn and count how many fall.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Brick>, ParseError> {
+        let mut bricks: Vec<Brick> = vec![];
+        for line in error::lines(input) {
+            let mut set_of_cubes: HashSet<Cube> = HashSet::new();
+            let (start, end) = line.split_once(line.text, "~")?;
+            let coords = |text: &'_ str| {
+                let coords = text.split(',').collect::<Vec<&str>>();
+                if coords.len() != 3 {
+                    return Err(line.error(text, "expected x,y,z"));
+                }
+                Ok((
+                    line.parse::<isize>(coords[0], "an x coordinate")?,
+                    line.parse::<isize>(coords[1], "a y coordinate")?,
+                    line.parse::<usize>(coords[2], "a z coordinate")?,
+                ))
+            };
+            let coords_start = coords(start)?;
+            let coords_end = coords(end)?;
+            if coords_start.0 > coords_end.0
+                || coords_start.1 > coords_end.1
+                || coords_start.2 > coords_end.2
+            {
+                return Err(
+                    line.error(end, "expected the end of the brick to come after the start")
+                );
+            }
+            let cubes = [
+                coords_start.0.abs_diff(coords_end.0),
+                coords_start.1.abs_diff(coords_end.1),
+                coords_start.2.abs_diff(coords_end.2),
+            ]
+            .into_iter()
+            .try_fold(1usize, |cubes, length| {
+                cubes.checked_mul(length.checked_add(1)?)
+            });
+            if cubes.is_none_or(|cubes| cubes > MAX_BRICK_CUBES) {
+                return Err(line.error(
+                    line.text,
+                    format!("expected a brick of at most {} cubes", MAX_BRICK_CUBES),
+                ));
+            }
+            for x in coords_start.0..=coords_end.0 {
+                for y in coords_start.1..=coords_end.1 {
+                    for z in coords_start.2..=coords_end.2 {
+                        set_of_cubes.insert(Cube { x, y, z });
+                    }
+                }
+            }
+            bricks.push(Brick {
+                cubes: set_of_cubes,
+                min_z: coords_start.2,
+            });
+        }
+        bricks.sort_by_key(|b| b.min_z);
+        Ok(bricks)
+    }
+
+    fn part1(&self, bricks: &Vec<Brick>) -> Result<Answer, SolveError> {
+        let bricks = settle(bricks);
+        Ok((0..bricks.len())
+            .filter(|i| count_falling_bricks(&bricks, *i) == 0)
+            .count()
+            .into())
+    }
+
+    fn part2(&self, bricks: &Vec<Brick>) -> Option<Result<Answer, SolveError>> {
+        let bricks = settle(bricks);
+        Some(Ok((0..bricks.len())
+            .map(|i| count_falling_bricks(&bricks, i))
+            .sum::<usize>()
+            .into()))
+    }
+
+    // Size is the number of bricks, each a line of up to four cubes in a 10 by 10 column as in the
+    // real input. Bricks are put in at random heights wherever they don't overlap anything.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(1200);
+        let height = size / 3 + 10;
+        let mut occupied: HashSet<Cube> = HashSet::new();
+        let mut lines = vec![];
+        while lines.len() < size {
+            let start = (rng.isize(0..10), rng.isize(0..10), rng.usize(1..=height));
+            let length = rng.usize(0..4);
+            let end = match rng.u8(..3) {
+                0 => (start.0 + length as isize, start.1, start.2),
+                1 => (start.0, start.1 + length as isize, start.2),
+                _ => (start.0, start.1, start.2 + length),
+            };
+            if end.0 >= 10 || end.1 >= 10 {
+                continue;
+            }
+            let cubes = (start.0..=end.0)
+                .flat_map(|x| (start.1..=end.1).map(move |y| (x, y)))
+                .flat_map(|(x, y)| (start.2..=end.2).map(move |z| Cube { x, y, z }))
+                .collect::<Vec<Cube>>();
+            if cubes.iter().any(|cube| occupied.contains(cube)) {
+                continue;
+            }
+            occupied.extend(cubes);
+            lines.push(format!(
+                "{},{},{}~{},{},{}",
+                start.0, start.1, start.2, end.0, end.1, end.2
+            ));
+        }
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day22.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        assert_eq!(Day22.part1(&input), Ok(Answer::Int(5)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        assert_eq!(Day22.part2(&input), Some(Ok(Answer::Int(7))));
+    }
+
+    #[test]
+    fn test_parse_huge_brick() {
+        let error = Day22.parse("0,0,1~0,0,99999999999\n").unwrap_err();
+        assert_eq!(error.message, "expected a brick of at most 10000 cubes");
+        assert!(Day22
+            .parse("-9000000000000000000,0,1~9000000000000000000,0,1\n")
+            .is_err());
+    }
+
+    // Lines like `1,0,1~1,2,1`. Each brick is a straight line of cubes.
+    fn input() -> impl Strategy<Value = String> {
+        let brick = (0..10isize, 0..10isize, 1..20usize, 0..3usize, 0..4usize);
+        prop::collection::vec(brick, 1..10).prop_map(|bricks| {
+            let lines = bricks
+                .iter()
+                .map(|&(x, y, z, axis, length)| {
+                    let (dx, dy, dz) = match axis {
+                        0 => (length as isize, 0, 0),
+                        1 => (0, length as isize, 0),
+                        _ => (0, 0, length),
+                    };
+                    format!("{},{},{}~{},{},{}", x, y, z, x + dx, y + dy, z + dz)
+                })
+                .collect::<Vec<String>>();
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day22, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day22.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,472 @@
+use std::collections::{HashMap, HashSet};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub enum TileType {
+    Path,
+    IcySlope((isize, isize)),
+}
+
+// Map of junctions to their adjacent junctions plus distance.
+type Nodes = HashMap<Junction, Vec<(Junction, usize)>>;
+type Junction = (isize, isize);
+
+// This function builds up the Nodes map by recursively traversing the path, but it's a bit of a
+// mess and I can't be bothered to tidy it up.
+fn build_junction_graph(
+    start: (isize, isize),
+    first_step: (isize, isize),
+    end: (isize, isize),
+    path: &HashMap<(isize, isize), TileType>,
+    junction_graph: &mut Nodes,
+) {
+    let mut path_to_next_junction: HashSet<(isize, isize)> = HashSet::new();
+    path_to_next_junction.insert(start);
+    path_to_next_junction.insert(first_step);
+    let mut next_step = first_step;
+    let mut distance = 0;
+    loop {
+        distance += 1;
+        let mut neighbours: Vec<(isize, isize)> = Vec::new();
+        for delta in [(0, 1), (1, 0), (-1, 0), (0, -1)] {
+            if path.contains_key(&(next_step.0 + delta.0, next_step.1 + delta.1))
+                && !path_to_next_junction.contains(&(next_step.0 + delta.0, next_step.1 + delta.1))
+            {
+                neighbours.push((next_step.0 + delta.0, next_step.1 + delta.1));
+            }
+        }
+        if neighbours.is_empty() {
+            // Either we've got to the end or it's a dead end, which isn't worth remembering.
+            if (next_step.0 - end.0).abs() + (next_step.1 - end.1).abs() == 1 {
+                junction_graph
+                    .entry(start)
+                    .or_default()
+                    .push((end, distance + 1));
+            }
+            break;
+        }
+        if neighbours.len() == 1 {
+            path_to_next_junction.insert(neighbours[0]);
+            next_step = neighbours[0];
+        } else {
+            if let std::collections::hash_map::Entry::Vacant(e) = junction_graph.entry(start) {
+                e.insert(vec![(next_step, distance)]);
+            } else {
+                junction_graph
+                    .get_mut(&start)
+                    .unwrap()
+                    .push((next_step, distance));
+            }
+            if !junction_graph.contains_key(&next_step) {
+                for delta in [(0, 1), (1, 0), (-1, 0), (0, -1)] {
+                    if path.contains_key(&(next_step.0 + delta.0, next_step.1 + delta.1)) {
+                        build_junction_graph(
+                            next_step,
+                            (next_step.0 + delta.0, next_step.1 + delta.1),
+                            end,
+                            path,
+                            junction_graph,
+                        );
+                    }
+                }
+            }
+            break;
+        }
+    }
+}
+
+// The longest walk from start to the end that starts off in this direction, or None if there's
+// no way to the end that way.
+fn count_max_steps_direction(
+    start: (isize, isize),
+    end: (isize, isize),
+    next_step: (isize, isize),
+    path: &HashMap<(isize, isize), TileType>,
+) -> Option<isize> {
+    let next = (start.0 + next_step.0, start.1 + next_step.1);
+    if next == end {
+        return Some(1);
+    }
+    match path.get(&next)? {
+        TileType::Path => {
+            let mut remaining_path = path.clone();
+            remaining_path.remove(&next);
+            count_max_steps(next, end, &remaining_path).map(|count| count + 1)
+        }
+        TileType::IcySlope(delta) => {
+            let slid_to = (next.0 + delta.0, next.1 + delta.1);
+            if !path.contains_key(&slid_to) {
+                return None;
+            }
+            let mut remaining_path = path.clone();
+            remaining_path.remove(&next);
+            remaining_path.remove(&slid_to);
+            count_max_steps(slid_to, end, &remaining_path).map(|count| count + 2)
+        }
+    }
+}
+
+fn count_max_steps(
+    start: (isize, isize),
+    end: (isize, isize),
+    path: &HashMap<(isize, isize), TileType>,
+) -> Option<isize> {
+    [(-1, 0), (1, 0), (0, 1), (0, -1)]
+        .into_iter()
+        .filter_map(|direction| count_max_steps_direction(start, end, direction, path))
+        .max()
+}
+
+fn count_max_steps_part2(
+    start: (isize, isize),
+    end: (isize, isize),
+    path: &Nodes,
+) -> Option<isize> {
+    let mut counts: HashSet<isize> = HashSet::new();
+    let mut remaining_path = path.clone();
+    remaining_path.remove(&start);
+    for j in path.get(&start).into_iter().flatten() {
+        if j.0 == end {
+            counts.insert(j.1 as isize);
+        } else if remaining_path.contains_key(&j.0) {
+            if let Some(count) = count_max_steps_part2(j.0, end, &remaining_path) {
+                counts.insert(j.1 as isize + count);
+            }
+        }
+    }
+    counts.iter().max().copied()
+}
+
+// Part 1 the part 2 way, to check the brute force against: shrink the map to a graph of
+// junctions and find the longest walk through that. Slopes can only be walked down, so some
+// trails only go one way. None if there's no way to the end.
+pub fn longest_hike_via_junctions(trails: &HikingTrails) -> Option<usize> {
+    let open = |tile: &Junction| {
+        *tile == trails.start || *tile == trails.end || trails.path.contains_key(tile)
+    };
+    let neighbours = |tile: Junction| {
+        [(0, 1), (1, 0), (-1, 0), (0, -1)]
+            .into_iter()
+            .map(move |delta| (tile.0 + delta.0, tile.1 + delta.1))
+            .filter(open)
+    };
+    let mut junctions = trails
+        .path
+        .keys()
+        .copied()
+        .filter(|tile| neighbours(*tile).count() > 2)
+        .collect::<HashSet<Junction>>();
+    junctions.extend([trails.start, trails.end]);
+
+    // Walk each way out of each junction until we get to the next one, unless we hit a dead end
+    // or a slope pointing the wrong way.
+    let mut graph: Nodes = HashMap::new();
+    for &junction in &junctions {
+        for first_step in neighbours(junction) {
+            let (mut previous, mut tile, mut distance) = (junction, first_step, 1);
+            loop {
+                if let Some(TileType::IcySlope(delta)) = trails.path.get(&tile) {
+                    let slid_to = (tile.0 + delta.0, tile.1 + delta.1);
+                    if slid_to == previous || !open(&slid_to) {
+                        break;
+                    }
+                }
+                if junctions.contains(&tile) {
+                    graph.entry(junction).or_default().push((tile, distance));
+                    break;
+                }
+                let mut next = neighbours(tile).filter(|next| *next != previous);
+                match (next.next(), next.next()) {
+                    (Some(next), None) => {
+                        previous = tile;
+                        tile = next;
+                        distance += 1;
+                    }
+                    _ => break,
+                }
+            }
+        }
+    }
+    longest_walk(&graph, trails.start, trails.end, &mut HashSet::new())
+}
+
+fn longest_walk(
+    graph: &Nodes,
+    from: Junction,
+    end: Junction,
+    visited: &mut HashSet<Junction>,
+) -> Option<usize> {
+    if from == end {
+        return Some(0);
+    }
+    visited.insert(from);
+    let longest = graph
+        .get(&from)
+        .into_iter()
+        .flatten()
+        .filter_map(|(next, distance)| {
+            if visited.contains(next) {
+                return None;
+            }
+            longest_walk(graph, *next, end, visited).map(|rest| distance + rest)
+        })
+        .max();
+    visited.remove(&from);
+    longest
+}
+
+fn no_way_to_the_end() -> SolveError {
+    SolveError::new("there's no way from the gap in the top row to the one in the bottom row")
+}
+
+pub struct HikingTrails {
+    pub path: HashMap<(isize, isize), TileType>,
+    pub start: (isize, isize),
+    pub end: (isize, isize),
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HikingTrails;
+
+    const TITLE: &'static str = "A Long Walk";
+    const NOTES: &'static str =
+        "Part 1 brute forces the longest walk. Part 2 shrinks the map to a graph of junctions and brute forces that.";
+
+    fn parse(&self, input: &str) -> Result<HikingTrails, ParseError> {
+        let lines = error::grid(input)?;
+        let mut path: HashMap<(isize, isize), TileType> = HashMap::new();
+        let height = lines.len();
+        let mut start: Option<(isize, isize)> = None;
+        let mut end: Option<(isize, isize)> = None;
+        for (r, line) in lines.iter().enumerate() {
+            let row = r as isize;
+            for (c, tile) in line.text.chars().enumerate() {
+                let col = c as isize;
+                match tile {
+                    '.' => {
+                        if row == 0 {
+                            start = Some((row, col));
+                        } else if row == height as isize - 1 {
+                            end = Some((row, col));
+                        } else {
+                            path.insert((row, col), TileType::Path);
+                        }
+                    }
+                    '^' => {
+                        path.insert((row, col), TileType::IcySlope((-1, 0)));
+                    }
+                    'v' => {
+                        path.insert((row, col), TileType::IcySlope((1, 0)));
+                    }
+                    '<' => {
+                        path.insert((row, col), TileType::IcySlope((0, -1)));
+                    }
+                    '>' => {
+                        path.insert((row, col), TileType::IcySlope((0, 1)));
+                    }
+                    '#' => (),
+                    _ => return Err(line.error_at(c, "expected ., #, ^, v, < or >")),
+                }
+            }
+        }
+        Ok(HikingTrails {
+            path,
+            start: start.ok_or_else(|| ParseError::missing(1, "expected a gap in the top row"))?,
+            end: end
+                .ok_or_else(|| ParseError::missing(height, "expected a gap in the bottom row"))?,
+        })
+    }
+
+    fn part1(&self, trails: &HikingTrails) -> Result<Answer, SolveError> {
+        count_max_steps(trails.start, trails.end, &trails.path)
+            .map(Answer::from)
+            .ok_or_else(no_way_to_the_end)
+    }
+
+    // Brute forcing part 2 was less successful. Simplify the problem by reducing the path to a
+    // graph of junctions, and brute force those. Still slow, but good enough for me.
+    fn part2(&self, trails: &HikingTrails) -> Option<Result<Answer, SolveError>> {
+        let (start, end) = (trails.start, trails.end);
+        let first_step = (start.0 + 1, start.1);
+        let mut junctions: Nodes = HashMap::new();
+        if first_step == end {
+            junctions.insert(start, vec![(end, 1)]);
+        } else if trails.path.contains_key(&first_step) {
+            build_junction_graph(start, first_step, end, &trails.path, &mut junctions);
+        }
+        Some(
+            count_max_steps_part2(start, end, &junctions)
+                .map(Answer::from)
+                .ok_or_else(no_way_to_the_end),
+        )
+    }
+
+    // Size is the number of junctions along each side of a square of them. Trails run straight
+    // between neighbouring junctions, with a few left out, and have slopes at each end pointing
+    // right or down, as in the real input, so part 1 can't double back. Every junction but the
+    // last has a way on, and none of the trails is a dead end, which part 2 relies on.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(6).max(2);
+        let last = size - 1;
+        // Where each row and column of junctions goes, with the trail from the start coming down
+        // to the first one and the trail to the end going down from the last.
+        let mut rows = vec![3];
+        let mut cols = vec![1];
+        for _ in 1..size {
+            rows.push(rows.last().unwrap() + rng.usize(4..=12));
+            cols.push(cols.last().unwrap() + rng.usize(4..=12));
+        }
+        let (height, width) = (rows[last] + 4, cols[last] + 2);
+
+        // Trails go right or down from a junction. Start with all of them and take some away.
+        let mut trails = HashSet::new();
+        for i in 0..size {
+            for j in 0..size {
+                if j < last {
+                    trails.insert(((i, j), (i, j + 1)));
+                }
+                if i < last {
+                    trails.insert(((i, j), (i + 1, j)));
+                }
+            }
+        }
+        let mut candidates = trails.iter().copied().collect::<Vec<_>>();
+        candidates.sort();
+        rng.shuffle(&mut candidates);
+        for trail in candidates.into_iter().take(size * size / 3) {
+            trails.remove(&trail);
+            let degree = |junction| {
+                let start_or_end = junction == (0, 0) || junction == (last, last);
+                trails
+                    .iter()
+                    .filter(|(a, b)| *a == junction || *b == junction)
+                    .count()
+                    + start_or_end as usize
+            };
+            let has_way_on =
+                |junction| junction == (last, last) || trails.iter().any(|(a, _)| *a == junction);
+            let mut reached = HashSet::from([(0, 0)]);
+            let mut queue = vec![(0, 0)];
+            while let Some(junction) = queue.pop() {
+                for (a, b) in &trails {
+                    for (from, to) in [(a, b), (b, a)] {
+                        if *from == junction && reached.insert(*to) {
+                            queue.push(*to);
+                        }
+                    }
+                }
+            }
+            if degree(trail.0) < 2
+                || degree(trail.1) < 2
+                || !has_way_on(trail.0)
+                || reached.len() < size * size
+            {
+                trails.insert(trail);
+            }
+        }
+
+        let mut grid = vec![vec!['#'; width]; height];
+        grid[0][cols[0]] = '.';
+        grid[1][cols[0]] = '.';
+        grid[2][cols[0]] = 'v';
+        grid[height - 3][cols[last]] = 'v';
+        grid[height - 2][cols[last]] = '.';
+        grid[height - 1][cols[last]] = '.';
+        for ((i, j), (k, l)) in trails {
+            let (from, to) = ((rows[i], cols[j]), (rows[k], cols[l]));
+            let cells = (from.0..=to.0).flat_map(|row| (from.1..=to.1).map(move |col| (row, col)));
+            for (row, col) in cells {
+                grid[row][col] = if (row, col) == from || (row, col) == to {
+                    '.'
+                } else if row == from.0 + 1 || row == to.0 - 1 {
+                    'v'
+                } else if col == from.1 + 1 || col == to.1 - 1 {
+                    '>'
+                } else {
+                    '.'
+                };
+            }
+        }
+        Some(generate::join_lines(
+            grid.into_iter()
+                .map(|row| row.into_iter().collect::<String>()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day23.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part1(&input), Ok(Answer::Int(94)));
+    }
+
+    #[test]
+    fn test_longest_hike_via_junctions() {
+        let input = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(longest_hike_via_junctions(&input), Some(94));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part2(&input), Some(Ok(Answer::Int(154))));
+    }
+
+    #[test]
+    fn test_without_junctions() {
+        let input = Day23.parse("#.###\n#...#\n###.#\n").unwrap();
+        assert_eq!(Day23.part1(&input), Ok(Answer::Int(4)));
+        assert_eq!(Day23.part2(&input), Some(Ok(Answer::Int(4))));
+    }
+
+    #[test]
+    fn test_no_way_to_the_end() {
+        for map in ["#.###\n##..#\n###.#\n", "#.###\n#.#.#\n###.#\n"] {
+            let input = Day23.parse(map).unwrap();
+            assert!(Day23.part1(&input).is_err());
+            assert!(matches!(Day23.part2(&input), Some(Err(_))));
+        }
+    }
+
+    // A grid of paths, forest and slopes, with a gap in the top and bottom rows.
+    fn input() -> impl Strategy<Value = String> {
+        let gaps = (any::<prop::sample::Index>(), any::<prop::sample::Index>());
+        (grammar::grid("#.><^v", 2..10, 1..10), gaps).prop_map(|(mut rows, (top, bottom))| {
+            let width = rows[0].len();
+            let last = rows.len() - 1;
+            for (row, gap) in [(0, top), (last, bottom)] {
+                let col = gap.index(width);
+                rows[row].replace_range(col..col + 1, ".");
+            }
+            generate::join_lines(&rows)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day23, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day23.parse(&input);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use z3::ast::Ast;
+use z3::*;
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+// The x and y bounds that the puzzle cares about intersections within.
+const TEST_AREA: RangeInclusive<f64> = 200000000000000.0..=400000000000000.0;
+
+#[derive(Debug)]
+pub struct Hailstone {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+    pub x_velocity: isize,
+    pub y_velocity: isize,
+    pub z_velocity: isize,
+}
+
+// Looking for these two statements to be true for some t and t':
+// h1.x + t*h1.x_velocity = h2.x + t'*h2.x_velocity
+// h1.y + t*h1.y_velocity = h2.y + t'*h2.y_velocity
+//
+// Rearranging:
+// (h1.x + t*h1.x_velocity - h2.x)/h2.x_velocity = t'
+// (h1.y + t*h1.y_velocity - h2.y)/h2.y_velocity = t'
+// (h1.x + t*h1.x_velocity - h2.x)/h2.x_velocity = (h1.y + t*h1.y_velocity - h2.y)/h2.y_velocity
+// (h1.x + t*h1.x_velocity - h2.x)*h2.y_velocity = (h1.y + t*h1.y_velocity - h2.y)*h2.x_velocity
+// h1.x*h2.y_velocity + t*h1.x_velocity*h2.y_velocity - h2.x*h2.y_velocity = h1.y*h2.x_velocity + t*h1.y_velocity*h2.x_velocity - h2.y*h2.x_velocity
+// t*h1.x_velocity*h2.y_velocity - t*h1.y_velocity*h2.x_velocity = h1.y*h2.x_velocity - h1.x*h2.y_velocity - h2.y*h2.x_velocity + h2.x*h2.y_velocity
+// t*(h1.x_velocity*h2.y_velocity - h1.y_velocity*h2.x_velocity) = h1.y*h2.x_velocity - h1.x*h2.y_velocity - h2.y*h2.x_velocity + h2.x*h2.y_velocity
+// t = (h1.y*h2.x_velocity - h1.x*h2.y_velocity - h2.y*h2.x_velocity + h2.x*h2.y_velocity)/(h1.x_velocity*h2.y_velocity - h1.y_velocity*h2.x_velocity)
+//
+// So, let's find if that t exists, and then check a) if both t and t' are positive (i.e. in the
+// future) and b) if the intersection point is within the bounds specified.
+fn paths_intersect(h1: &Hailstone, h2: &Hailstone, test_area: &RangeInclusive<f64>) -> bool {
+    if h1.x_velocity * h2.y_velocity == h1.y_velocity * h2.x_velocity {
+        return false;
+    }
+    let t: f64 = (h1.y * h2.x_velocity - h1.x * h2.y_velocity - h2.y * h2.x_velocity
+        + h2.y_velocity * h2.x) as f64
+        / (h1.x_velocity * h2.y_velocity - h1.y_velocity * h2.x_velocity) as f64;
+    // Check if t is in the past
+    if t < 0.0 {
+        return false;
+    }
+    // Check if t' is in the past
+    if ((h1.x as f64 + t * h1.x_velocity as f64 - h2.x as f64) / h2.x_velocity as f64) < 0.0 {
+        return false;
+    }
+    let x = h1.x as f64 + t * h1.x_velocity as f64;
+    let y = h1.y as f64 + t * h1.y_velocity as f64;
+    test_area.contains(&x) && test_area.contains(&y)
+}
+
+fn count_intersections(hailstones: &[Hailstone], test_area: &RangeInclusive<f64>) -> usize {
+    let mut count: usize = 0;
+    for (i, hailstone1) in hailstones.iter().enumerate() {
+        for hailstone2 in hailstones.iter().skip(i + 1) {
+            if paths_intersect(hailstone1, hailstone2, test_area) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+// x + t1*x_velocity = 260346828765750 + t1*64
+// y + t1*y_velocity = 357833641339849 + t1*-114
+// z + t1*z_velocity = 229809969824403 + t1*106
+// x + t2*x_velocity = 340220726383465 + t2*-79
+// y + t2*y_velocity = 393110064924024 + t2*-61
+// z + t2*z_velocity = 226146987100003 + t2*158
+// x + t3*x_velocity = 11361697274707 + t3*328
+// y + t3*y_velocity = 101596061919750 + t3*162
+// z + t3*z_velocity = 46099495948720 + t3*333
+
+// Variables are t1, t2, t3, x, y, z, x_velocity, y_velocity, z_velocity
+// 260346828765750, 357833641339849, 229809969824403 @ 64, -114, 106
+// 340220726383465, 393110064924024, 226146987100003 @ -79, -61, 158
+// 11361697274707, 101596061919750, 46099495948720 @ 328, 162, 333
+// t1*x_velocity - t1*h1.x_velocity = h1.x - x
+// t1 = (h1.x - x)/(x_velocity - h1.x_velocity)
+// t1 = (h1.y - y)/(y_velocity - h1.y_velocity)
+// t1 = (h1.z - z)/(z_velocity - h1.z_velocity)
+// (h1.x - x)/(x_velocity - h1.x_velocity) = (h1.y - y)/(y_velocity - h1.y_velocity)
+// x = h1.x - (h1.y - y)*(x_velocity - h1.x_velocity)/(y_velocity - h1.y_velocity)
+// (h2.x - x)/(x_velocity - h2.x_velocity) = (h2.y - y)/(y_velocity - h2.y_velocity)
+// (h2.x - h1.x + (h1.y - y)*(x_velocity - h1.x_velocity)/(y_velocity - h1.y_velocity))/(x_velocity - h2.x_velocity) = (h2.y - y)/(y_velocity - h2.y_velocity)
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    const TITLE: &'static str = "Never Tell Me The Odds";
+    const NOTES: &'static str =
+        "Part 1 intersects each pair of paths in 2D. Part 2 hands the rock's throw to z3.";
+
+    fn parse(&self, input: &str) -> Result<Vec<Hailstone>, ParseError> {
+        let mut hailstones: Vec<Hailstone> = vec![];
+        for line in error::lines(input) {
+            let (positions, velocities) = line.split_once(line.text, "@")?;
+            let triple = |text: &'_ str, what: &str| {
+                let numbers = text
+                    .split(',')
+                    .map(|x| line.parse::<isize>(x, what))
+                    .collect::<Result<Vec<isize>, ParseError>>()?;
+                if numbers.len() != 3 {
+                    return Err(line.error(text, format!("expected three of {}", what)));
+                }
+                Ok(numbers)
+            };
+            let positions = triple(positions, "a position")?;
+            let velocities = triple(velocities, "a velocity")?;
+            hailstones.push(Hailstone {
+                x: positions[0],
+                y: positions[1],
+                z: positions[2],
+                x_velocity: velocities[0],
+                y_velocity: velocities[1],
+                z_velocity: velocities[2],
+            });
+        }
+        Ok(hailstones)
+    }
+
+    fn part1(&self, hailstones: &Vec<Hailstone>) -> Result<Answer, SolveError> {
+        Ok(count_intersections(hailstones, &TEST_AREA).into())
+    }
+
+    // For part 2, just by looking at the first three hailstones we can generate 9 equations with
+    // 9 unknowns (x, y, z are our starting position and x_velocity, y_velocity, z_velocity are
+    // our velocities; t1, t2, and t3 are the times at which we intersect with the first three
+    // hailstones):
+    // x + t1*x_velocity = h1.x + t1*h1.x_velocity
+    // y + t1*y_velocity = h1.y + t1*h1.y_velocity
+    // z + t1*z_velocity = h1.z + t1*h1.z_velocity
+    // x + t2*x_velocity = h2.x + t2*h2.x_velocity
+    // y + t2*y_velocity = h2.y + t2*h2.y_velocity
+    // z + t2*z_velocity = h2.z + t2*h2.z_velocity
+    // x + t3*x_velocity = h3.x + t3*h3.x_velocity
+    // y + t3*y_velocity = h3.y + t3*h3.y_velocity
+    // z + t3*z_velocity = h3.z + t3*h3.z_velocity
+    //
+    // Solve these with the Z3 library.
+    fn part2(&self, hailstones: &Vec<Hailstone>) -> Option<Result<Answer, SolveError>> {
+        let config = Config::new();
+        let ctx = Context::new(&config);
+        let solver = Solver::new(&ctx);
+        let x = ast::Int::new_const(&ctx, "x");
+        let y = ast::Int::new_const(&ctx, "y");
+        let z = ast::Int::new_const(&ctx, "z");
+        let x_velocity = ast::Int::new_const(&ctx, "x_velocity");
+        let y_velocity = ast::Int::new_const(&ctx, "y_velocity");
+        let z_velocity = ast::Int::new_const(&ctx, "z_velocity");
+
+        for (i, hailstone) in hailstones.iter().take(3).enumerate() {
+            let t = ast::Int::new_const(&ctx, format!("t{}", i + 1));
+            let h_x = ast::Int::from_i64(&ctx, hailstone.x as i64);
+            let h_y = ast::Int::from_i64(&ctx, hailstone.y as i64);
+            let h_z = ast::Int::from_i64(&ctx, hailstone.z as i64);
+            let h_x_velocity = ast::Int::from_i64(&ctx, hailstone.x_velocity as i64);
+            let h_y_velocity = ast::Int::from_i64(&ctx, hailstone.y_velocity as i64);
+            let h_z_velocity = ast::Int::from_i64(&ctx, hailstone.z_velocity as i64);
+            solver.assert(&(&x + (&t * &x_velocity))._eq(&(&h_x + (&t * &h_x_velocity))));
+            solver.assert(&(&y + (&t * &y_velocity))._eq(&(&h_y + (&t * &h_y_velocity))));
+            solver.assert(&(&z + (&t * &z_velocity))._eq(&(&h_z + (&t * &h_z_velocity))));
+        }
+        solver.check();
+        let model = solver.get_model().unwrap();
+        let res = model.eval(&(&x + &y + &z), true).unwrap();
+        Some(Ok(res
+            .as_i64()
+            .map(Answer::from)
+            .unwrap_or_else(|| res.to_string().into())))
+    }
+
+    // Size is the number of hailstones. There really is a rock that hits them all: we throw it
+    // first, then work out where each hailstone must have started to be hit at a random time.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let rock = [(); 3].map(|_| rng.i64(200_000_000_000_000..400_000_000_000_000));
+        let rock_velocity = [(); 3].map(|_| rng.i64(-300..=300));
+        let mut times = HashSet::new();
+        let mut lines = vec![];
+        while lines.len() < size.unwrap_or(300) {
+            let time = rng.i64(100_000_000_000..1_000_000_000_000);
+            // How much faster than the rock the hailstone's going, which mustn't be not at all.
+            let difference = [(); 3].map(|_| rng.i64(-300..=300));
+            if difference == [0; 3] || !times.insert(time) {
+                continue;
+            }
+            let position = [0, 1, 2].map(|i| rock[i] - time * difference[i]);
+            let velocity = [0, 1, 2].map(|i| rock_velocity[i] + difference[i]);
+            lines.push(format!(
+                "{}, {}, {} @ {}, {}, {}",
+                position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+            ));
+        }
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day24.txt");
+
+    // The example's test area is much smaller than the real one.
+    #[test]
+    fn test_part1() {
+        let input = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(count_intersections(&input, &(7.0..=27.0)), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(Day24.part2(&input), Some(Ok(Answer::Int(47))));
+    }
+
+    // Lines like `19, 13, 30 @ -2,  1, -2`.
+    fn input() -> impl Strategy<Value = String> {
+        let hailstone = (
+            prop::collection::vec(-1000..1000isize, 3),
+            prop::collection::vec(-10..10isize, 3),
+        );
+        prop::collection::vec(hailstone, 1..6).prop_map(|hailstones| {
+            let lines = hailstones
+                .iter()
+                .map(|(position, velocity)| {
+                    format!(
+                        "{} @ {}",
+                        grammar::join(position, ", "),
+                        grammar::join(velocity, ", ")
+                    )
+                })
+                .collect::<Vec<String>>();
+            generate::join_lines(&lines)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day24, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day24.parse(&input);
+        }
+    }
+}
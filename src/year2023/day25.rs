@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use fastrand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{self, ParseError, SolveError};
+use crate::generate;
+use crate::solution::Solution;
+
+// Split the edges into sets of disconnected nodes (using a very inefficient algorithm).
+fn solve(edges: &[(String, String)]) -> Option<usize> {
+    let mut sets: Vec<HashSet<String>> = vec![];
+    for edge in edges {
+        let mut existing_sets_indices: Vec<usize> = vec![];
+        // Find existing sets that contain either node of the edge
+        for (index, s) in sets.iter_mut().enumerate() {
+            if s.contains(&edge.0) || s.contains(&edge.1) {
+                existing_sets_indices.push(index);
+                s.insert(edge.0.clone());
+                s.insert(edge.1.clone());
+            }
+        }
+        if existing_sets_indices.is_empty() {
+            // If there were no existing sets, create a new one
+            let mut new_set = HashSet::new();
+            new_set.insert(edge.0.clone());
+            new_set.insert(edge.1.clone());
+            sets.push(new_set.to_owned());
+        } else if existing_sets_indices.len() > 1 {
+            // If there were multiple existing sets, we need to now combine them
+            let combine_at_index = existing_sets_indices.first().unwrap();
+            let mut new_set = sets.get(*combine_at_index).unwrap().to_owned();
+            for index in existing_sets_indices.iter().rev() {
+                if index != combine_at_index {
+                    new_set.extend(sets.get(*index).unwrap().clone());
+                    sets.remove(*index);
+                }
+            }
+            sets[*combine_at_index] = new_set;
+        }
+    }
+    if sets.len() == 2 {
+        Some(sets[0].len() * sets[1].len())
+    } else {
+        None
+    }
+}
+
+// The three edges that split our input in two, found by eye from the graph drawn in part 1.
+const KEY_EDGES: [(&str, &str); 3] = [("vkp", "kfr"), ("qpp", "vnm"), ("bff", "rhk")];
+
+// Remove each of the given edges from the graph, whichever way round it was written down.
+fn remove_edges(edges: &mut Vec<(String, String)>, to_remove: &[(&str, &str)]) {
+    edges.retain(|(a, b)| {
+        !to_remove
+            .iter()
+            .any(|(c, d)| (a == c && b == d) || (a == d && b == c))
+    });
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<(String, String)>;
+
+    const TITLE: &'static str = "Snowverload";
+    const NOTES: &'static str =
+        "Cut the three wires spotted in a graphviz drawing and count each half. No part 2.";
+
+    // Build graph of edges
+    fn parse(&self, input: &str) -> Result<Vec<(String, String)>, ParseError> {
+        let mut edges: Vec<(String, String)> = vec![];
+        for line in error::lines(input) {
+            let (component, connections) = line.split_once(line.text, ": ")?;
+            for c in connections.split_whitespace() {
+                edges.push((component.to_owned(), c.to_owned()));
+            }
+        }
+        Ok(edges)
+    }
+
+    fn part1(&self, edges: &Vec<(String, String)>) -> Result<Answer, SolveError> {
+        let mut edges = edges.to_vec();
+
+        // Draw graph using graphviz and find three key edges
+        let mut graph = String::from("graph {\n");
+        for (a, b) in &edges {
+            graph += &format!("  {} -- {};\n", a, b);
+        }
+        graph += "}";
+        // Run with -vv to print the graph, then install graphviz and run the following command on
+        // it (minus the log prefix) to display it.
+        //   dot -Tsvg -Kneato day25graph.dot > day25graph.svg
+        //
+        // I've shamelessly stolen this command from someone else so I have no idea what any of the
+        // flags do.
+        log::trace!("{}", graph);
+
+        // Remove the three key edges, determined by inspecting the graph generated above
+        remove_edges(&mut edges, &KEY_EDGES);
+
+        // Solve. Other inputs need other edges cutting, so they won't split in two.
+        solve(&edges).map(Answer::from).ok_or_else(|| {
+            SolveError::new("cutting our three wires doesn't split this graph in two")
+        })
+    }
+
+    // Size is the number of components. They're split into two groups joined by exactly three
+    // wires, the three that part 1 cuts. Inside each group everything has at least four wires,
+    // so (all but certainly) there's no other way to split the graph with three cuts.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let size = size.unwrap_or(1500).max(10);
+        let (left, right): (Vec<&str>, Vec<&str>) = KEY_EDGES.iter().copied().unzip();
+        let taken = [left.clone(), right.clone()].concat();
+        let mut others = generate::names(rng, size - 6, 3, "abcdefghijklmnopqrstuvwxyz", &taken);
+        let right_others = others.split_off(rng.usize(2..=size - 8));
+        let mut edges = KEY_EDGES
+            .iter()
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect::<Vec<(String, String)>>();
+        for (keys, others) in [(left, others), (right, right_others)] {
+            let mut group = keys
+                .iter()
+                .map(|key| key.to_string())
+                .collect::<Vec<String>>();
+            group.extend(others);
+            rng.shuffle(&mut group);
+            // A random tree first, so the group hangs together, then extra wires.
+            let mut wires = vec![BTreeSet::new(); group.len()];
+            for i in 1..group.len() {
+                let j = rng.usize(..i);
+                wires[i].insert(j);
+                wires[j].insert(i);
+            }
+            for i in 0..group.len() {
+                while wires[i].len() < 4 {
+                    let j = rng.usize(..group.len());
+                    if j != i {
+                        wires[i].insert(j);
+                        wires[j].insert(i);
+                    }
+                }
+            }
+            for (i, connected) in wires.iter().enumerate() {
+                edges.extend(
+                    connected
+                        .iter()
+                        .filter(|j| **j > i)
+                        .map(|j| (group[i].clone(), group[*j].clone())),
+                );
+            }
+        }
+        // Each wire is listed under one of its ends, chosen at random.
+        let mut listed: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (a, b) in edges {
+            let (a, b) = if rng.bool() { (a, b) } else { (b, a) };
+            listed.entry(a).or_default().push(b);
+        }
+        let mut lines = listed
+            .into_iter()
+            .map(|(component, connected)| format!("{}: {}", component, connected.join(" ")))
+            .collect::<Vec<String>>();
+        rng.shuffle(&mut lines);
+        Some(generate::join_lines(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../tests/fixtures/2023/day25.txt");
+
+    // Part 1 removes the edges for our input, so cut the example's edges by hand instead.
+    #[test]
+    fn test_part1() {
+        let mut edges = Day25.parse(EXAMPLE).unwrap();
+        remove_edges(
+            &mut edges,
+            &[("hfx", "pzl"), ("bvb", "cmg"), ("nvd", "jqt")],
+        );
+        assert_eq!(solve(&edges), Some(54));
+    }
+
+    // Cutting our wires doesn't split anyone else's graph, which used to panic.
+    #[test]
+    fn test_part1_without_our_wires() {
+        let input = Day25.parse(EXAMPLE).unwrap();
+        assert!(Day25.part1(&input).is_err());
+        let input = Day25.parse("").unwrap();
+        assert!(Day25.part1(&input).is_err());
+    }
+
+    // Lines like `jqt: rhn xhk nvd`.
+    fn input() -> impl Strategy<Value = String> {
+        let name = || grammar::chars("abcdefghijklmnopqrstuvwxyz", 3);
+        prop::collection::vec((name(), prop::collection::vec(name(), 1..5)), 1..10).prop_map(
+            |components| {
+                let lines = components
+                    .iter()
+                    .map(|(component, connections)| {
+                        format!("{}: {}", component, connections.join(" "))
+                    })
+                    .collect::<Vec<String>>();
+                generate::join_lines(&lines)
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_valid(input in input()) {
+            grammar::parses(&Day25, &input)?;
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in grammar::mutated(input())) {
+            let _ = Day25.parse(&input);
+        }
+    }
+}